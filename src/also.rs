@@ -0,0 +1,43 @@
+//! The [`Also`] convenience trait, a Kotlin-style alias for [`Tap`]
+
+use crate::Tap;
+
+pub trait Also: Sized {
+    #[must_use]
+    fn also<F: FnOnce(&Self)>(self, f: F) -> Self;
+}
+
+impl<T> Also for T {
+    /// Calls a function with a reference to any `T`.
+    ///
+    /// This is an alias for [`Tap::tap`], named after Kotlin's `also`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Kotlin: val numbers = mutableListOf(1, 2, 3).also { println("Got ${it.size}") }
+    /// use treats::Also;
+    ///
+    /// let numbers = vec![1, 2, 3].also(|it| println!("Got {}", it.len()));
+    ///
+    /// assert_eq!(numbers, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn also<F: FnOnce(&Self)>(self, f: F) -> Self { self.tap(f) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn also() {
+        let mut seen = 0;
+
+        assert_eq! {
+            42.also(|n| seen = *n),
+            42
+        };
+        assert_eq!(seen, 42);
+    }
+}
@@ -0,0 +1,59 @@
+//! The [`BoolExt`] convenience trait for [`bool`]s
+
+pub trait BoolExt {
+    fn then_value<T>(self, value: T) -> Option<T>;
+
+    fn then_else<T>(self, yes: T, no: T) -> T;
+}
+
+impl BoolExt for bool {
+    /// Yields `Some(value)` if `true`, or [`None`] otherwise.
+    ///
+    /// This is equivalent to [`bool::then_some`], and is eager unlike
+    /// [`bool::then`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::BoolExt;
+    ///
+    /// fn verbosity_flag(verbose: bool) -> Option<&'static str> { verbose.then_value("--verbose") }
+    ///
+    /// assert_eq!(verbosity_flag(true), Some("--verbose"));
+    /// assert_eq!(verbosity_flag(false), None);
+    /// ```
+    #[inline]
+    fn then_value<T>(self, value: T) -> Option<T> { self.then_some(value) }
+
+    /// Yields `yes` if `true`, or `no` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::BoolExt;
+    ///
+    /// fn plural(n: usize) -> &'static str { (n == 1).then_else("", "s") }
+    ///
+    /// assert_eq!(format!("1 file{}", plural(1)), "1 file");
+    /// assert_eq!(format!("2 file{}", plural(2)), "2 files");
+    /// ```
+    #[inline]
+    fn then_else<T>(self, yes: T, no: T) -> T { if self { yes } else { no } }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn then_value() {
+        assert_eq!(true.then_value(42), Some(42));
+        assert_eq!(false.then_value(42), None);
+    }
+
+    #[test]
+    fn then_else() {
+        assert_eq!(true.then_else("yes", "no"), "yes");
+        assert_eq!(false.then_else("yes", "no"), "no");
+    }
+}
@@ -0,0 +1,100 @@
+//! The [`Clamp`] convenience trait for [`PartialOrd`] types
+
+pub trait Clamp {
+    #[must_use]
+    fn clamp_min(self, min: Self) -> Self;
+
+    #[must_use]
+    fn clamp_max(self, max: Self) -> Self;
+
+    #[must_use]
+    fn clamp_to(self, lo: Self, hi: Self) -> Self;
+}
+
+impl<T: PartialOrd> Clamp for T {
+    /// Yields `min` if less than `min`, or the original value otherwise.
+    ///
+    /// If the comparison is undefined, like for [`f64::NAN`], the original
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Clamp;
+    ///
+    /// assert_eq!((-3).clamp_min(0), 0);
+    /// assert_eq!(7.5.clamp_min(0.0), 7.5);
+    /// ```
+    #[inline]
+    fn clamp_min(self, min: Self) -> Self { if self < min { min } else { self } }
+
+    /// Yields `max` if greater than `max`, or the original value otherwise.
+    ///
+    /// If the comparison is undefined, like for [`f64::NAN`], the original
+    /// value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Clamp;
+    ///
+    /// assert_eq!(300.clamp_max(255), 255);
+    /// assert_eq!(0.5.clamp_max(1.0), 0.5);
+    /// ```
+    #[inline]
+    fn clamp_max(self, max: Self) -> Self { if self > max { max } else { self } }
+
+    /// Restricts a value to the range `lo..=hi`.
+    ///
+    /// Unlike [`Ord::clamp`], this works for any [`PartialOrd`] type and
+    /// doesn't panic. If the comparison is undefined, like for [`f64::NAN`],
+    /// the original value is returned. If `lo > hi`, `hi` wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Clamp;
+    ///
+    /// fn volume(v: f64) -> f64 { v.clamp_to(0.0, 1.0) }
+    ///
+    /// assert_eq!(volume(1.5), 1.0);
+    /// assert_eq!(volume(-0.5), 0.0);
+    /// assert!(volume(f64::NAN).is_nan());
+    /// ```
+    #[inline]
+    fn clamp_to(self, lo: Self, hi: Self) -> Self { self.clamp_min(lo).clamp_max(hi) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_integers() {
+        assert_eq!((-3).clamp_min(0), 0);
+        assert_eq!(3.clamp_min(0), 3);
+        assert_eq!(300_u16.clamp_max(255), 255);
+        assert_eq!(3.clamp_max(255), 3);
+        assert_eq!(42.clamp_to(0, 10), 10);
+        assert_eq!((-42).clamp_to(0, 10), 0);
+        assert_eq!(5.clamp_to(0, 10), 5);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn clamp_floats() {
+        assert_eq!((-0.5).clamp_min(0.0), 0.0);
+        assert_eq!(1.5.clamp_max(1.0), 1.0);
+        assert_eq!(0.25.clamp_to(0.0, 1.0), 0.25);
+        assert_eq!(f64::INFINITY.clamp_to(0.0, 1.0), 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn clamp_nan() {
+        assert!(f64::NAN.clamp_min(0.0).is_nan());
+        assert!(f64::NAN.clamp_max(1.0).is_nan());
+        assert!(f64::NAN.clamp_to(0.0, 1.0).is_nan());
+        assert_eq!(0.5.clamp_to(f64::NAN, f64::NAN), 0.5);
+    }
+}
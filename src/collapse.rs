@@ -0,0 +1,76 @@
+//! The [`OptionCollapse`] and [`ResultCollapse`] convenience traits for nested
+//! [`Option`]s and [`Result`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+pub trait OptionCollapse<T> {
+    fn collapse(self) -> Option<T>;
+}
+
+impl<T> OptionCollapse<T> for Option<Option<T>> {
+    /// Collapses an [`Option<Option<T>>`] into an [`Option<T>`].
+    ///
+    /// This is an alias for [`Option::flatten`], named to match
+    /// [`ResultCollapse::collapse_err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionCollapse;
+    ///
+    /// let args = ["treats", "--jobs"];
+    /// let jobs = args.iter().position(|a| *a == "--jobs").map(|i| args.get(i + 1));
+    ///
+    /// assert_eq!(jobs.collapse(), None);
+    /// ```
+    #[inline]
+    fn collapse(self) -> Option<T> { self.flatten() }
+}
+
+pub trait ResultCollapse<T, E> {
+    fn collapse_err(self) -> Result<T, E>;
+}
+
+impl<T, E> ResultCollapse<T, E> for Result<Result<T, E>, E> {
+    /// Collapses a [`Result<Result<T, E>, E>`] into a [`Result<T, E>`].
+    ///
+    /// Either error is kept as is, so both layers must share an error type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::ResultCollapse;
+    ///
+    /// fn parse_port(s: &str) -> Result<u16, String> {
+    ///     s.strip_prefix(':')
+    ///         .ok_or_else(|| format!("missing ':' in {s:?}"))
+    ///         .map(|p| p.parse().map_err(|e| format!("bad port {p:?}: {e}")))
+    ///         .collapse_err()
+    /// }
+    ///
+    /// assert_eq!(parse_port(":8080"), Ok(8080));
+    /// assert!(parse_port("8080").is_err());
+    /// assert!(parse_port(":http").is_err());
+    /// ```
+    #[inline]
+    fn collapse_err(self) -> Result<T, E> { self.and_then(|r| r) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapse_option() {
+        assert_eq!(Some(Some(42)).collapse(), Some(42));
+        assert_eq!(Some(None::<u8>).collapse(), None);
+        assert_eq!(None::<Option<u8>>.collapse(), None);
+    }
+
+    #[test]
+    fn collapse_result() {
+        assert_eq!(Ok::<_, &str>(Ok(42)).collapse_err(), Ok(42));
+        assert_eq!(Ok::<Result<u8, _>, _>(Err("inner")).collapse_err(), Err("inner"));
+        assert_eq!(Err::<Result<u8, _>, _>("outer").collapse_err(), Err("outer"));
+    }
+}
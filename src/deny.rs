@@ -0,0 +1,116 @@
+//! Deny values for [`Result`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+pub trait Deny<T, E> {
+    fn deny<F>(self, f: F, err: E) -> Result<T, E>
+    where
+        F: FnOnce(&T) -> bool;
+
+    fn deny_if(self, condition: bool, err: E) -> Result<T, E>;
+}
+
+impl<T, E> Deny<T, E> for Result<T, E> {
+    /// Turns a specific [`Ok`] value into `err`
+    ///
+    /// This is the inverse of `Permit` from the `permit` feature. Existing
+    /// errors are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Parse a port, but deny port 0
+    /// use treats::Deny;
+    ///
+    /// fn parse_port(s: &str) -> Result<u16, String> {
+    ///     s.parse::<u16>()
+    ///         .map_err(|e| e.to_string())
+    ///         .deny(|&port| port == 0, "port must not be 0".to_string())
+    /// }
+    ///
+    /// assert_eq!(parse_port("8080"), Ok(8080));
+    /// assert_eq!(parse_port("0"), Err("port must not be 0".to_string()));
+    /// assert!(parse_port("http").is_err());
+    /// ```
+    #[inline]
+    fn deny<F>(self, f: F, err: E) -> Self
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        match self {
+            | Ok(ref t) if f(t) => Err(err), // deny the value and return the error
+            | Ok(t) => Ok(t),                // return the original value if not denied
+            | Err(e) => Err(e),              // if result is an error, return it
+        }
+    }
+
+    /// Turns any [`Ok`] value into `err` if `condition` holds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Deny;
+    ///
+    /// fn parse_name(s: &str, strict: bool) -> Result<&str, &'static str> {
+    ///     Ok(s.trim()).deny_if(strict && s.trim() != s, "name has surrounding whitespace")
+    /// }
+    ///
+    /// assert_eq!(parse_name(" tox ", false), Ok("tox"));
+    /// assert_eq!(parse_name(" tox ", true), Err("name has surrounding whitespace"));
+    /// ```
+    #[inline]
+    fn deny_if(self, condition: bool, err: E) -> Self {
+        match self {
+            | Ok(_) if condition => Err(err), // deny the value and return the error
+            | Ok(t) => Ok(t),                 // return the original value if not denied
+            | Err(e) => Err(e),               // if result is an error, return it
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deny_a_value() {
+        let x: Result<&str, &str> = Ok("");
+
+        assert_eq! {
+            x.deny(|s| s.is_empty(), "empty body"),
+            Err("empty body")
+        }
+    }
+
+    #[test]
+    fn deny_nothing() {
+        let x: Result<&str, &str> = Ok("body");
+
+        assert_eq! {
+            x.deny(|s| s.is_empty(), "empty body"),
+            Ok("body")
+        }
+    }
+
+    #[test]
+    fn deny_keeps_error() {
+        let x: Result<&str, &str> = Err("original error");
+
+        assert_eq! {
+            x.deny(|_| true, "empty body"),
+            Err("original error")
+        }
+    }
+
+    #[test]
+    fn deny_if() {
+        let x: Result<i32, &str> = Ok(-3);
+
+        assert_eq!(x.deny_if(true, "denied"), Err("denied"));
+        assert_eq!(x.deny_if(false, "denied"), Ok(-3));
+
+        let x: Result<i32, &str> = Err("original error");
+
+        assert_eq!(x.deny_if(true, "denied"), Err("original error"));
+    }
+}
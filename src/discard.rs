@@ -2,6 +2,13 @@
 
 pub trait Discard {
     fn discard(self);
+
+    fn discard_ref(&self);
+
+    #[must_use]
+    fn discard_if(self, condition: bool) -> Option<Self>
+    where
+        Self: Sized;
 }
 
 impl<T> Discard for T {
@@ -35,6 +42,47 @@ impl<T> Discard for T {
     /// ```
     #[inline]
     fn discard(self) {}
+
+    /// Discard a `&T` without consuming it.
+    ///
+    /// This does nothing, but documents that a value is intentionally unused
+    /// where moving it isn't possible or desired.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Mutex;
+    ///
+    /// use treats::Discard;
+    ///
+    /// static LOCK: Mutex<()> = Mutex::new(());
+    ///
+    /// fn exclusive() {
+    ///     let guard = LOCK.lock().unwrap();
+    ///     // The guard is intentionally held until the end of the scope
+    ///     guard.discard_ref();
+    /// }
+    /// ```
+    #[inline]
+    fn discard_ref(&self) {}
+
+    /// Discard a `T` if `condition` is `true`, yielding it otherwise.
+    ///
+    /// Unlike [`discard`](Discard::discard), this keeps the value around for
+    /// further use in a chain when `condition` is `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// use treats::Discard;
+    ///
+    /// fn label(name: &str, hidden: bool) -> Option<String> {
+    ///     name.to_uppercase().discard_if(hidden)
+    /// }
+    ///
+    /// assert_eq!(label("docs", false).as_deref(), Some("DOCS"));
+    /// assert_eq!(label("internal", true), None);
+    /// ```
+    #[inline]
+    fn discard_if(self, condition: bool) -> Option<Self> { (!condition).then_some(self) }
 }
 
 #[cfg(test)]
@@ -57,4 +105,31 @@ mod tests {
             ()
         };
     }
+
+    #[test]
+    fn discard_ref() {
+        struct Guard;
+
+        let guard = Guard;
+
+        assert_eq! {
+            guard.discard_ref(),
+            ()
+        };
+
+        let Guard = guard;
+    }
+
+    #[test]
+    fn discard_if() {
+        assert_eq! {
+            42.discard_if(true),
+            None
+        };
+
+        assert_eq! {
+            "kept".discard_if(false),
+            Some("kept")
+        };
+    }
 }
@@ -0,0 +1,44 @@
+//! The [`DiscardDebug`] convenience trait
+
+use std::fmt::Debug;
+
+pub trait DiscardDebug {
+    fn debug_discard(self);
+}
+
+impl<T: Debug> DiscardDebug for T {
+    /// Discard any `T`, printing its [`Debug`] representation to stderr first.
+    ///
+    /// This is like [`dbg!`], but ends a chain instead of returning the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use treats::DiscardDebug;
+    ///
+    /// fn sizes(names: &[&str]) {
+    ///     // Prints "[5, 3]"
+    ///     names.iter().map(|n| n.len()).collect::<Vec<_>>().debug_discard();
+    /// }
+    ///
+    /// sizes(&["tox", "treats"]);
+    /// ```
+    #[inline]
+    fn debug_discard(self) { eprintln!("{self:?}") }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_discard() {
+        assert_eq! {
+            vec![1, 2, 3].debug_discard(),
+            ()
+        };
+        assert_eq! {
+            Err::<u8, _>("some error").debug_discard(),
+            ()
+        };
+    }
+}
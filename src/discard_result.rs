@@ -0,0 +1,102 @@
+//! The [`DiscardResult`] convenience trait for [`Result`]s
+
+use std::fmt::Debug;
+
+pub trait DiscardResult<T, E> {
+    fn discard_ok(self)
+    where
+        E: Debug;
+
+    #[must_use]
+    fn discard_err(self) -> Option<T>;
+}
+
+impl<T, E> DiscardResult<T, E> for Result<T, E> {
+    /// Discard the [`Ok`] value of a [`Result`], printing the error to stderr
+    /// if [`Err`].
+    ///
+    /// Unlike `let _ = ...`, this doesn't silently drop errors.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use treats::DiscardResult;
+    ///
+    /// fn greet() {
+    ///     // We only care about the happy path, but want to hear about failures
+    ///     writeln!(std::io::stdout(), "Hello!").discard_ok();
+    /// }
+    /// ```
+    #[inline]
+    fn discard_ok(self)
+    where
+        E: Debug,
+    {
+        if let Err(e) = self {
+            eprintln!("{e:?}");
+        }
+    }
+
+    /// Discard the error of a [`Result`], keeping the [`Ok`] value.
+    ///
+    /// This is functionally equivalent to [`Result::ok`] but reads like the
+    /// rest of the `discard` family.
+    ///
+    /// # Examples
+    /// ```
+    /// use treats::DiscardResult;
+    ///
+    /// fn parse_ports(s: &str) -> Vec<u16> {
+    ///     // Skip anything that isn't a valid port
+    ///     s.split(',').filter_map(|p| p.trim().parse().discard_err()).collect()
+    /// }
+    ///
+    /// assert_eq!(parse_ports("80, 443, http, 8080"), [80, 443, 8080]);
+    /// ```
+    #[inline]
+    fn discard_err(self) -> Option<T> { self.ok() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discard_ok() {
+        let x: Result<i32, &str> = Ok(-3);
+
+        assert_eq! {
+            x.discard_ok(),
+            ()
+        };
+    }
+
+    #[test]
+    fn discard_ok_logs_err() {
+        // The test harness captures the printed error
+        let x: Result<i32, &str> = Err("some error");
+
+        assert_eq! {
+            x.discard_ok(),
+            ()
+        };
+    }
+
+    #[test]
+    fn discard_err() {
+        let x: Result<i32, &str> = Ok(-3);
+
+        assert_eq! {
+            x.discard_err(),
+            Some(-3)
+        };
+
+        let x: Result<i32, &str> = Err("some error");
+
+        assert_eq! {
+            x.discard_err(),
+            None
+        };
+    }
+}
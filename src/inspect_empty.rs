@@ -0,0 +1,111 @@
+//! The [`InspectEmpty`] convenience trait for collections and strings
+
+pub trait InspectEmpty {
+    #[must_use]
+    fn inspect_empty<F: FnOnce()>(self, f: F) -> Self;
+}
+
+impl<T> InspectEmpty for Vec<T> {
+    /// Calls a function if the [`Vec`] [`is_empty`](Vec::is_empty).
+    ///
+    /// Returns the original [`Vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::InspectEmpty;
+    ///
+    /// fn matches<'a>(names: &[&'a str], query: &str) -> Vec<&'a str> {
+    ///     names
+    ///         .iter()
+    ///         .copied()
+    ///         .filter(|name| name.contains(query))
+    ///         .collect::<Vec<_>>()
+    ///         .inspect_empty(|| eprintln!("No matches for {query}"))
+    /// }
+    /// ```
+    #[inline]
+    fn inspect_empty<F: FnOnce()>(self, f: F) -> Self {
+        if self.is_empty() {
+            f();
+        }
+
+        self
+    }
+}
+
+impl InspectEmpty for String {
+    /// Calls a function if the [`String`] [`is_empty`](String::is_empty).
+    ///
+    /// Returns the original [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::InspectEmpty;
+    ///
+    /// fn read_name(input: &str) -> String {
+    ///     input.trim().to_string().inspect_empty(|| eprintln!("Name is blank"))
+    /// }
+    /// ```
+    #[inline]
+    fn inspect_empty<F: FnOnce()>(self, f: F) -> Self {
+        if self.is_empty() {
+            f();
+        }
+
+        self
+    }
+}
+
+impl InspectEmpty for &str {
+    /// Calls a function if the [`str`] [`is_empty`](str::is_empty).
+    ///
+    /// Returns the original [`str`].
+    #[inline]
+    fn inspect_empty<F: FnOnce()>(self, f: F) -> Self {
+        if self.is_empty() {
+            f();
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_inspect_empty() {
+        let mut inspected = false;
+        let _ = vec![1, 2, 3].inspect_empty(|| inspected = true);
+        assert!(!inspected);
+
+        let mut inspected = false;
+        let _ = Vec::<u8>::new().inspect_empty(|| inspected = true);
+        assert!(inspected);
+    }
+
+    #[test]
+    fn string_inspect_empty() {
+        let mut inspected = false;
+        let _ = String::from("treats").inspect_empty(|| inspected = true);
+        assert!(!inspected);
+
+        let mut inspected = false;
+        let _ = String::new().inspect_empty(|| inspected = true);
+        assert!(inspected);
+    }
+
+    #[test]
+    fn str_inspect_empty() {
+        let mut inspected = false;
+        assert_eq!("treats".inspect_empty(|| inspected = true), "treats");
+        assert!(!inspected);
+
+        let mut inspected = false;
+        assert_eq!("".inspect_empty(|| inspected = true), "");
+        assert!(inspected);
+    }
+}
@@ -0,0 +1,74 @@
+//! The [`AsyncInspectNone`] convenience trait for [`Option`]s
+
+use core::future::Future;
+
+pub trait AsyncInspectNone<T>: Sized {
+    fn inspect_none_async<F, Fut>(self, f: F) -> impl Future<Output = Self>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ()>;
+}
+
+impl<T> AsyncInspectNone<T> for Option<T> {
+    /// Awaits a function's future if [`None`].
+    ///
+    /// Resolves to the original option. This is the async counterpart of
+    /// `InspectNone::inspect_none` from the `inspect_none` feature.
+    ///
+    /// This desugars to an `async fn`, but returns `impl Future` so the trait
+    /// doesn't trip the `async_fn_in_trait` lint. The returned future is
+    /// [`Send`] whenever `T`, `F`, and `Fut` are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::AsyncInspectNone;
+    ///
+    /// async fn flush_metrics() {}
+    ///
+    /// async fn find_user(name: &str) -> Option<u32> {
+    ///     name.parse()
+    ///         .ok()
+    ///         .inspect_none_async(flush_metrics)
+    ///         .await
+    /// }
+    /// ```
+    async fn inspect_none_async<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        if self.is_none() {
+            f().await;
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn inspect_none_async_some() {
+        let mut inspected = false;
+        let optional_number: Option<u8> = Some(42);
+        let optional_number = optional_number
+            .inspect_none_async(|| async { inspected = true })
+            .await;
+        assert_eq!(optional_number, Some(42));
+        assert!(!inspected);
+    }
+
+    #[tokio::test]
+    async fn inspect_none_async_none() {
+        let mut inspected = false;
+        let optional_number: Option<u8> = None;
+        let optional_number = optional_number
+            .inspect_none_async(|| async { inspected = true })
+            .await;
+        assert_eq!(optional_number, None);
+        assert!(inspected);
+    }
+}
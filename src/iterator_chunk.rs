@@ -0,0 +1,66 @@
+//! The [`IteratorChunkExt`] convenience trait for [`Iterator`]s
+
+use std::iter;
+
+pub trait IteratorChunkExt: Iterator + Sized {
+    fn chunks_owned(self, size: usize) -> impl Iterator<Item = Vec<Self::Item>>;
+}
+
+impl<I: Iterator> IteratorChunkExt for I {
+    /// Groups the items of an [`Iterator`] into [`Vec`]s of `size` items.
+    ///
+    /// The last chunk holds whatever is left, so it may be smaller. Unlike
+    /// [`slice::chunks`], this works on any [`Iterator`], and an empty one
+    /// yields no chunks at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::IteratorChunkExt;
+    ///
+    /// let batches = (1..=5).chunks_owned(2).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(batches, [vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    #[track_caller]
+    fn chunks_owned(mut self, size: usize) -> impl Iterator<Item = Vec<Self::Item>> {
+        assert!(size > 0, "chunk size must be greater than 0");
+
+        iter::from_fn(move || {
+            let chunk = self.by_ref().take(size).collect::<Vec<_>>();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_owned_exact() {
+        let chunks = (1..=6).chunks_owned(3).collect::<Vec<_>>();
+
+        assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn chunks_owned_remainder() {
+        let chunks = "treats".chars().chunks_owned(4).collect::<Vec<_>>();
+
+        assert_eq!(chunks, [vec!['t', 'r', 'e', 'a'], vec!['t', 's']]);
+    }
+
+    #[test]
+    fn chunks_owned_empty() {
+        assert_eq!(iter::empty::<u8>().chunks_owned(2).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be greater than 0")]
+    fn chunks_owned_zero() { let _ = (1..=3).chunks_owned(0); }
+}
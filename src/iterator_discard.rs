@@ -0,0 +1,37 @@
+//! The [`IteratorDiscard`] convenience trait for [`Iterator`]s
+
+pub trait IteratorDiscard {
+    fn discard_all(self);
+}
+
+impl<I: Iterator> IteratorDiscard for I {
+    /// Drive an [`Iterator`] to completion, discarding every item.
+    ///
+    /// This is functionally equivalent to `.for_each(drop)` but reads more
+    /// like `Discard::discard` from the `discard` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use treats::IteratorDiscard;
+    ///
+    /// fn remove_all(paths: &[&str]) {
+    ///     paths.iter().map(std::fs::remove_file).discard_all();
+    /// }
+    /// ```
+    #[inline]
+    fn discard_all(self) { for _ in self {} }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discard_all() {
+        let mut count = 0;
+
+        (0..5).map(|_| count += 1).discard_all();
+
+        assert_eq!(count, 5);
+    }
+}
@@ -0,0 +1,79 @@
+//! The [`IteratorGroupExt`] convenience trait for [`Iterator`]s
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub trait IteratorGroupExt: Iterator + Sized {
+    fn group_by_key<K, F>(self, f: F) -> HashMap<K, Vec<Self::Item>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K;
+}
+
+impl<I: Iterator> IteratorGroupExt for I {
+    /// Groups the items of an [`Iterator`] into a [`HashMap`] by the key `f`
+    /// computes for each.
+    ///
+    /// Items keep their original order within each group. An empty
+    /// [`Iterator`] yields an empty [`HashMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::IteratorGroupExt;
+    ///
+    /// let files = ["lib.rs", "README.md", "path.rs", "LICENSE"];
+    /// let by_ext = files.into_iter().group_by_key(|f| f.rsplit_once('.').map(|(_, ext)| ext));
+    ///
+    /// assert_eq!(by_ext[&Some("rs")], ["lib.rs", "path.rs"]);
+    /// assert_eq!(by_ext[&None], ["LICENSE"]);
+    /// ```
+    fn group_by_key<K, F>(self, mut f: F) -> HashMap<K, Vec<Self::Item>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut groups = HashMap::<K, Vec<_>>::new();
+        for item in self {
+            groups.entry(f(&item)).or_default().push(item);
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_by_parity() {
+        let groups = (1..=7).group_by_key(|n| n % 2 == 0);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&true], [2, 4, 6]);
+        assert_eq!(groups[&false], [1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn group_by_first_char() {
+        let words = ["tox", "apple", "treats", "avocado", "wtf"];
+        let groups = words.into_iter().group_by_key(|w| w.chars().next());
+
+        assert_eq!(groups[&Some('t')], ["tox", "treats"]);
+        assert_eq!(groups[&Some('a')], ["apple", "avocado"]);
+        assert_eq!(groups[&Some('w')], ["wtf"]);
+    }
+
+    #[test]
+    fn group_by_empty() {
+        assert!(std::iter::empty::<u8>().group_by_key(|n| *n).is_empty());
+    }
+
+    #[test]
+    fn group_by_same_key() {
+        let groups = [3, 1, 2].into_iter().group_by_key(|_| ());
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[&()], [3, 1, 2]);
+    }
+}
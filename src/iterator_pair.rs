@@ -0,0 +1,80 @@
+//! The [`IteratorPairExt`] convenience trait for [`Iterator`]s
+
+use core::iter;
+
+pub trait IteratorPairExt: Iterator + Sized {
+    fn pairs(self) -> impl Iterator<Item = (Self::Item, Self::Item)>
+    where
+        Self::Item: Clone;
+}
+
+impl<I: Iterator> IteratorPairExt for I {
+    /// Yields each item of an [`Iterator`] paired with the next one.
+    ///
+    /// Pairs overlap, like [`slice::windows`] with a size of 2, but this works
+    /// on any [`Iterator`] by cloning each item once to keep it for the next
+    /// pair. Fewer than 2 items yield no pairs at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::IteratorPairExt;
+    ///
+    /// let readings = [3, 5, 4, 8];
+    /// let deltas = readings.into_iter().pairs().map(|(a, b)| b - a).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(deltas, [2, -1, 4]);
+    /// ```
+    fn pairs(mut self) -> impl Iterator<Item = (Self::Item, Self::Item)>
+    where
+        Self::Item: Clone,
+    {
+        let mut prev = None;
+        iter::from_fn(move || {
+            if prev.is_none() {
+                prev = Some(self.next()?);
+            }
+            let next = self.next()?;
+            Some((prev.replace(next.clone())?, next))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn pairs() {
+        let pairs = [1, 2, 3, 4].into_iter().pairs().collect::<Vec<_>>();
+
+        assert_eq!(pairs, [(1, 2), (2, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn pairs_owned() {
+        let words = ["tox", "wtf", "treats"].map(String::from);
+        let pairs = words.into_iter().pairs().map(|(a, b)| a + &b).collect::<Vec<_>>();
+
+        assert_eq!(pairs, ["toxwtf", "wtftreats"]);
+    }
+
+    #[test]
+    fn pairs_empty() {
+        assert_eq!(iter::empty::<u8>().pairs().count(), 0);
+        assert_eq!(iter::once(1).pairs().count(), 0);
+    }
+
+    #[test]
+    fn pairs_fused() {
+        let mut pairs = [1, 2].into_iter().pairs();
+
+        assert_eq!(pairs.next(), Some((1, 2)));
+        assert_eq!(pairs.next(), None);
+        assert_eq!(pairs.next(), None);
+    }
+}
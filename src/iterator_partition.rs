@@ -0,0 +1,83 @@
+//! The [`IteratorPartitionExt`] convenience trait for [`Iterator`]s of
+//! [`Result`]s
+
+pub trait IteratorPartitionExt<T, E> {
+    fn partition_results<A, B>(self) -> (A, B)
+    where
+        A: FromIterator<T>,
+        B: FromIterator<E>;
+}
+
+impl<I, T, E> IteratorPartitionExt<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    /// Collects the [`Ok`] values and the [`Err`]s of an [`Iterator`]
+    /// separately.
+    ///
+    /// Unlike collecting into a [`Result`], this doesn't stop at the first
+    /// error. Both collections keep the original order, and either may be
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::IteratorPartitionExt;
+    ///
+    /// let (ports, invalid): (Vec<u16>, Vec<_>) =
+    ///     ["80", "http", "443", "99999"].iter().map(|p| p.parse::<u16>()).partition_results();
+    ///
+    /// assert_eq!(ports, [80, 443]);
+    /// assert_eq!(invalid.len(), 2);
+    /// ```
+    fn partition_results<A, B>(self) -> (A, B)
+    where
+        A: FromIterator<T>,
+        B: FromIterator<E>,
+    {
+        let mut errs = Vec::new();
+        let oks = self.filter_map(|result| result.map_err(|e| errs.push(e)).ok()).collect();
+        (oks, errs.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn partition_mixed() {
+        let results = [Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+        let (oks, errs): (Vec<_>, Vec<_>) = results.into_iter().partition_results();
+
+        assert_eq!(oks, [1, 2, 3]);
+        assert_eq!(errs, ["a", "b"]);
+    }
+
+    #[test]
+    fn partition_all_ok() {
+        let (oks, errs): (Vec<_>, Vec<&str>) = (1..=3).map(Ok).partition_results();
+
+        assert_eq!(oks, [1, 2, 3]);
+        assert!(errs.is_empty());
+    }
+
+    #[test]
+    fn partition_all_err() {
+        let (oks, errs): (Vec<u8>, Vec<_>) = ["a", "b"].into_iter().map(Err).partition_results();
+
+        assert!(oks.is_empty());
+        assert_eq!(errs, ["a", "b"]);
+    }
+
+    #[test]
+    fn partition_other_collections() {
+        let results = [Ok('t'), Err(1), Ok('o'), Err(1), Ok('x')];
+        let (oks, errs): (String, HashSet<_>) = results.into_iter().partition_results();
+
+        assert_eq!(oks, "tox");
+        assert_eq!(errs, HashSet::from([1]));
+    }
+}
@@ -0,0 +1,89 @@
+//! The [`IteratorPermit`] convenience trait for [`Iterator`]s of [`Result`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+pub trait IteratorPermit<T, E> {
+    fn collect_permit<C, F>(self, f: F) -> Result<C, E>
+    where
+        C: FromIterator<T>,
+        F: FnMut(&E) -> bool;
+}
+
+impl<I, T, E> IteratorPermit<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    /// Collects the [`Ok`] values of an [`Iterator`], skipping permitted errors
+    ///
+    /// This is `Permit::permit` from the `permit` feature at the scale of an
+    /// [`Iterator`]. Items whose error `f` permits are dropped, and the first
+    /// error that isn't permitted stops iteration and is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::IteratorPermit;
+    ///
+    /// // Read whichever config files exist, but fail on anything else
+    /// let configs: Vec<String> = ["/etc/treats.toml", "/path/to/nonexistent/treats.toml"]
+    ///     .iter()
+    ///     .map(std::fs::read_to_string)
+    ///     .collect_permit(|e| e.kind() == ErrorKind::NotFound)
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    fn collect_permit<C, F>(self, mut f: F) -> Result<C, E>
+    where
+        C: FromIterator<T>,
+        F: FnMut(&E) -> bool,
+    {
+        self.filter(|result| result.as_ref().err().is_none_or(|e| !f(e))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+
+    use std::vec;
+    use std::vec::Vec;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum TestError {
+        Skippable,
+        Fatal,
+    }
+
+    #[test]
+    fn collect_permit_skips_permitted() {
+        let items = vec![Ok(1), Err(TestError::Skippable), Ok(2), Err(TestError::Skippable), Ok(3)];
+
+        assert_eq! {
+            items.into_iter().collect_permit::<Vec<_>, _>(|e| *e == TestError::Skippable),
+            Ok(vec![1, 2, 3])
+        };
+    }
+
+    #[test]
+    fn collect_permit_stops_on_fatal() {
+        let mut seen = 0;
+        let items = vec![Ok(1), Err(TestError::Skippable), Err(TestError::Fatal), Ok(2)];
+
+        let collected: Result<Vec<i32>, _> = items
+            .into_iter()
+            .inspect(|_| seen += 1)
+            .collect_permit(|e| *e == TestError::Skippable);
+        assert_eq!(collected, Err(TestError::Fatal));
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn collect_permit_empty() {
+        let items: Vec<Result<i32, TestError>> = Vec::new();
+
+        assert_eq!(items.into_iter().collect_permit::<Vec<_>, _>(|_| false), Ok(vec![]));
+    }
+}
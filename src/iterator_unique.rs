@@ -0,0 +1,93 @@
+//! The [`IteratorUniqueExt`] convenience trait for [`Iterator`]s
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+pub trait IteratorUniqueExt: Iterator + Sized {
+    fn unique_by<K, F>(self, f: F) -> impl Iterator<Item = Self::Item>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K;
+}
+
+impl<I: Iterator> IteratorUniqueExt for I {
+    /// Yields only the first item of an [`Iterator`] for each key `f` computes.
+    ///
+    /// Later items with a key that was already seen are dropped. Unlike
+    /// `VecExt::dedup_preserve_order` from the `vec_ext` feature, this is
+    /// lazy and works on any [`Iterator`], only keeping the keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::IteratorUniqueExt;
+    ///
+    /// let files = ["lib.rs", "README.md", "path.rs", "LICENSE"];
+    /// let first_per_ext = files
+    ///     .into_iter()
+    ///     .unique_by(|f| f.rsplit_once('.').map(|(_, ext)| ext))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(first_per_ext, ["lib.rs", "README.md", "LICENSE"]);
+    /// ```
+    fn unique_by<K, F>(self, mut f: F) -> impl Iterator<Item = Self::Item>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut seen = HashSet::new();
+        self.filter(move |item| seen.insert(f(item)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter;
+
+    #[derive(Debug, PartialEq)]
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    #[test]
+    fn unique_by_id() {
+        let users = [
+            User { id: 1, name: "tox" },
+            User { id: 2, name: "wtf" },
+            User { id: 1, name: "tox again" },
+            User { id: 3, name: "treats" },
+            User { id: 2, name: "wtf again" },
+        ];
+        let names = users.into_iter().unique_by(|u| u.id).map(|u| u.name).collect::<Vec<_>>();
+
+        assert_eq!(names, ["tox", "wtf", "treats"]);
+    }
+
+    #[test]
+    fn unique_by_same_key() {
+        let users = [User { id: 1, name: "tox" }, User { id: 2, name: "wtf" }];
+        let unique = users.into_iter().unique_by(|_| ()).collect::<Vec<_>>();
+
+        assert_eq!(unique, [User { id: 1, name: "tox" }]);
+    }
+
+    #[test]
+    fn unique_by_empty() {
+        assert_eq!(iter::empty::<u8>().unique_by(|n| *n).count(), 0);
+    }
+
+    #[test]
+    fn unique_by_lazy() {
+        let mut calls = 0;
+        let third = (1..)
+            .inspect(|_| calls += 1)
+            .unique_by(|n| n % 3)
+            .nth(2);
+
+        assert_eq!(third, Some(3));
+        assert_eq!(calls, 3);
+    }
+}
@@ -7,15 +7,172 @@
 #[cfg(feature = "path_to_string")] mod path;
 #[cfg(feature = "path_to_string")] pub use path::*;
 
+#[cfg(feature = "path_relative")] mod path_relative;
+#[cfg(feature = "path_relative")] pub use path_relative::*;
+
+#[cfg(feature = "path_contain")] mod path_contain;
+#[cfg(feature = "path_contain")] pub use path_contain::*;
+
+#[cfg(feature = "path_extension")] mod path_extension;
+#[cfg(feature = "path_extension")] pub use path_extension::*;
+
+#[cfg(feature = "path_ensure")] mod path_ensure;
+#[cfg(feature = "path_ensure")] pub use path_ensure::*;
+
+#[cfg(feature = "path_read")] mod path_read;
+#[cfg(feature = "path_read")] pub use path_read::*;
+
+#[cfg(feature = "path_home")] mod path_home;
+#[cfg(feature = "path_home")] pub use path_home::*;
+
+#[cfg(feature = "path_join")] mod path_join;
+#[cfg(feature = "path_join")] pub use path_join::*;
+
+#[cfg(feature = "path_dir")] mod path_dir;
+#[cfg(feature = "path_dir")] pub use path_dir::*;
+
+#[cfg(feature = "path_depth")] mod path_depth;
+#[cfg(feature = "path_depth")] pub use path_depth::*;
+
+#[cfg(feature = "path_meta")] mod path_meta;
+#[cfg(feature = "path_meta")] pub use path_meta::*;
+
+#[cfg(feature = "os_str")] mod os_str;
+#[cfg(feature = "os_str")] pub use os_str::*;
+
+#[cfg(feature = "string_ext")] mod string_ext;
+#[cfg(feature = "string_ext")] pub use string_ext::*;
+
+#[cfg(feature = "str_width")] mod str_width;
+#[cfg(feature = "str_width")] pub use str_width::*;
+
+#[cfg(feature = "str_ext")] mod str_ext;
+#[cfg(feature = "str_ext")] pub use str_ext::*;
+
 #[cfg(feature = "inspect_none")] mod inspect_none;
 #[cfg(feature = "inspect_none")] pub use inspect_none::*;
 
+#[cfg(feature = "async")] mod inspect_none_async;
+#[cfg(feature = "async")] pub use inspect_none_async::*;
+
+#[cfg(feature = "inspect_empty")] mod inspect_empty;
+#[cfg(feature = "inspect_empty")] pub use inspect_empty::*;
+
+#[cfg(feature = "non_empty")] mod non_empty;
+#[cfg(feature = "non_empty")] pub use non_empty::*;
+
+#[cfg(feature = "vec_ext")] mod vec_ext;
+#[cfg(feature = "vec_ext")] pub use vec_ext::*;
+
+#[cfg(feature = "option_err")] mod option_err;
+#[cfg(feature = "option_err")] pub use option_err::*;
+
+#[cfg(feature = "option_ext")] mod option_ext;
+#[cfg(feature = "option_ext")] pub use option_ext::*;
+
+#[cfg(feature = "option_zip")] mod option_zip;
+#[cfg(feature = "option_zip")] pub use option_zip::*;
+
+#[cfg(feature = "option_result")] mod option_result;
+#[cfg(feature = "option_result")] pub use option_result::*;
+
+#[cfg(feature = "option_inspect_some")] mod option_inspect_some;
+#[cfg(feature = "option_inspect_some")] pub use option_inspect_some::*;
+
+#[cfg(feature = "result_inspect")] mod result_inspect;
+#[cfg(feature = "result_inspect")] pub use result_inspect::*;
+
+#[cfg(feature = "result_ext")] mod result_ext;
+#[cfg(feature = "result_ext")] pub use result_ext::*;
+
+#[cfg(feature = "tap")] mod tap;
+#[cfg(feature = "tap")] pub use tap::*;
+
+#[cfg(feature = "also")] mod also;
+#[cfg(feature = "also")] pub use also::*;
+
+#[cfg(feature = "pipe")] mod pipe;
+#[cfg(feature = "pipe")] pub use pipe::*;
+
 #[cfg(feature = "discard")] mod discard;
 #[cfg(feature = "discard")] pub use discard::*;
 
+#[cfg(feature = "iterator_discard")] mod iterator_discard;
+#[cfg(feature = "iterator_discard")] pub use iterator_discard::*;
+
+#[cfg(feature = "iterator_permit")] mod iterator_permit;
+#[cfg(feature = "iterator_permit")] pub use iterator_permit::*;
+
+#[cfg(feature = "iterator_pair")] mod iterator_pair;
+#[cfg(feature = "iterator_pair")] pub use iterator_pair::*;
+
+#[cfg(feature = "iterator_chunk")] mod iterator_chunk;
+#[cfg(feature = "iterator_chunk")] pub use iterator_chunk::*;
+
+#[cfg(feature = "iterator_group")] mod iterator_group;
+#[cfg(feature = "iterator_group")] pub use iterator_group::*;
+
+#[cfg(feature = "iterator_partition")] mod iterator_partition;
+#[cfg(feature = "iterator_partition")] pub use iterator_partition::*;
+
+#[cfg(feature = "iterator_unique")] mod iterator_unique;
+#[cfg(feature = "iterator_unique")] pub use iterator_unique::*;
+
+#[cfg(feature = "discard_result")] mod discard_result;
+#[cfg(feature = "discard_result")] pub use discard_result::*;
+
+#[cfg(feature = "discard_debug")] mod discard_debug;
+#[cfg(feature = "discard_debug")] pub use discard_debug::*;
+
+#[cfg(feature = "once_lock")] mod once_lock;
+#[cfg(feature = "once_lock")] pub use once_lock::*;
+
+#[cfg(feature = "timed")] mod timed;
+#[cfg(feature = "timed")] pub use timed::*;
+
 #[cfg(feature = "permit")] mod permit;
 #[cfg(feature = "permit")] pub use permit::*;
 
+#[cfg(feature = "permit_option")] mod permit_option;
+#[cfg(feature = "permit_option")] pub use permit_option::*;
+
+#[cfg(feature = "permit_io")] mod permit_io;
+#[cfg(feature = "permit_io")] pub use permit_io::*;
+
+#[cfg(feature = "permit_exit")] mod permit_exit;
+#[cfg(feature = "permit_exit")] pub use permit_exit::*;
+
+#[cfg(feature = "anyhow")] mod permit_anyhow;
+#[cfg(feature = "anyhow")] pub use permit_anyhow::*;
+
+#[cfg(feature = "log")] mod permit_log;
+#[cfg(feature = "log")] pub use permit_log::*;
+
+#[cfg(feature = "deny")] mod deny;
+#[cfg(feature = "deny")] pub use deny::*;
+
+#[cfg(feature = "bool_ext")] mod bool_ext;
+#[cfg(feature = "bool_ext")] pub use bool_ext::*;
+
+#[cfg(feature = "clamp")] mod clamp;
+#[cfg(feature = "clamp")] pub use clamp::*;
+
+#[cfg(feature = "swap")] mod swap;
+#[cfg(feature = "swap")] pub use swap::*;
+
+#[cfg(feature = "collapse")] mod collapse;
+#[cfg(feature = "collapse")] pub use collapse::*;
+
+#[cfg(feature = "retry")] mod retry;
+#[cfg(feature = "retry")] pub use retry::*;
+
+#[cfg(feature = "slice_ext")] mod slice_ext;
+#[cfg(feature = "slice_ext")] pub use slice_ext::*;
+
+#[cfg(feature = "serde")] pub mod serde;
+
+#[cfg(all(test, feature = "std"))] pub(crate) mod test_util;
+
 #[cfg(test)]
 #[allow(clippy::useless_attribute)]
 #[allow(unused_imports)]
@@ -0,0 +1,163 @@
+//! The [`NonEmptyExt`] convenience trait for collections and strings
+
+#![allow(clippy::missing_errors_doc)]
+
+use std::collections::HashMap;
+
+pub trait NonEmptyExt: Sized {
+    fn non_empty(self) -> Option<Self>;
+
+    fn err_if_empty<E, F: FnOnce() -> E>(self, f: F) -> Result<Self, E>;
+}
+
+impl<T> NonEmptyExt for Vec<T> {
+    /// Yields `Some(self)` unless the [`Vec`] [`is_empty`](Vec::is_empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::NonEmptyExt;
+    ///
+    /// fn first_even(numbers: Vec<u32>) -> Option<u32> {
+    ///     let evens = numbers.into_iter().filter(|n| n % 2 == 0).collect::<Vec<_>>().non_empty()?;
+    ///     evens.first().copied()
+    /// }
+    ///
+    /// assert_eq!(first_even(vec![1, 4, 6]), Some(4));
+    /// assert_eq!(first_even(vec![1, 3]), None);
+    /// ```
+    #[inline]
+    fn non_empty(self) -> Option<Self> { (!self.is_empty()).then_some(self) }
+
+    /// Yields `Ok(self)` unless the [`Vec`] is empty, or the error from `f`
+    /// otherwise.
+    ///
+    /// This is [`non_empty`](NonEmptyExt::non_empty) for validation, so empty
+    /// inputs can be rejected with `?`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `f` if empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::NonEmptyExt;
+    ///
+    /// fn parse_names(input: &str) -> Result<Vec<&str>, String> {
+    ///     let names = input.split(',').filter(|n| !n.is_empty()).collect::<Vec<_>>();
+    ///     names.err_if_empty(|| "expected at least one name".to_string())
+    /// }
+    ///
+    /// assert_eq!(parse_names("tox,wtf"), Ok(vec!["tox", "wtf"]));
+    /// assert!(parse_names(",,").is_err());
+    /// ```
+    #[inline]
+    fn err_if_empty<E, F: FnOnce() -> E>(self, f: F) -> Result<Self, E> {
+        self.non_empty().ok_or_else(f)
+    }
+}
+
+impl NonEmptyExt for String {
+    /// Yields `Some(self)` unless the [`String`]
+    /// [`is_empty`](String::is_empty).
+    ///
+    /// Whitespace-only strings aren't empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::NonEmptyExt;
+    ///
+    /// let name = String::new().non_empty().unwrap_or_else(|| "anonymous".to_string());
+    /// assert_eq!(name, "anonymous");
+    /// ```
+    #[inline]
+    fn non_empty(self) -> Option<Self> { (!self.is_empty()).then_some(self) }
+
+    /// Yields `Ok(self)` unless the [`String`] is empty, or the error from `f`
+    /// otherwise.
+    ///
+    /// Whitespace-only strings aren't empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `f` if empty.
+    #[inline]
+    fn err_if_empty<E, F: FnOnce() -> E>(self, f: F) -> Result<Self, E> {
+        self.non_empty().ok_or_else(f)
+    }
+}
+
+impl<K, V, S> NonEmptyExt for HashMap<K, V, S> {
+    /// Yields `Some(self)` unless the [`HashMap`]
+    /// [`is_empty`](HashMap::is_empty).
+    #[inline]
+    fn non_empty(self) -> Option<Self> { (!self.is_empty()).then_some(self) }
+
+    /// Yields `Ok(self)` unless the [`HashMap`] is empty, or the error from `f`
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `f` if empty.
+    #[inline]
+    fn err_if_empty<E, F: FnOnce() -> E>(self, f: F) -> Result<Self, E> {
+        self.non_empty().ok_or_else(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_non_empty() {
+        assert_eq!(vec![1, 2, 3].non_empty(), Some(vec![1, 2, 3]));
+        assert_eq!(Vec::<u8>::new().non_empty(), None);
+    }
+
+    #[test]
+    fn string_non_empty() {
+        assert_eq!(String::from("treats").non_empty().as_deref(), Some("treats"));
+        assert_eq!(String::from(" \t\n").non_empty().as_deref(), Some(" \t\n"));
+        assert_eq!(String::new().non_empty(), None);
+    }
+
+    #[test]
+    fn hash_map_non_empty() {
+        let map = HashMap::from([("treats", 1)]);
+
+        assert_eq!(map.clone().non_empty(), Some(map));
+        assert_eq!(HashMap::<u8, u8>::new().non_empty(), None);
+    }
+
+    #[test]
+    fn vec_err_if_empty() {
+        assert_eq!(vec![1, 2, 3].err_if_empty(|| "empty"), Ok(vec![1, 2, 3]));
+        assert_eq!(Vec::<u8>::new().err_if_empty(|| "empty"), Err("empty"));
+    }
+
+    #[test]
+    fn string_err_if_empty() {
+        assert_eq!(String::from("treats").err_if_empty(|| "empty").as_deref(), Ok("treats"));
+        assert_eq!(String::from(" \t\n").err_if_empty(|| "empty").as_deref(), Ok(" \t\n"));
+        assert_eq!(String::new().err_if_empty(|| "empty"), Err("empty"));
+    }
+
+    #[test]
+    fn hash_map_err_if_empty() {
+        let map = HashMap::from([("treats", 1)]);
+
+        assert_eq!(map.clone().err_if_empty(|| "empty"), Ok(map));
+        assert_eq!(HashMap::<u8, u8>::new().err_if_empty(|| "empty"), Err("empty"));
+    }
+
+    #[test]
+    fn err_if_empty_lazy() {
+        let mut called = false;
+
+        let _ = vec![1].err_if_empty(|| called = true);
+        assert!(!called);
+    }
+}
@@ -0,0 +1,106 @@
+//! The [`OnceLockExt`] convenience trait for [`OnceLock`]s
+
+use std::sync::OnceLock;
+
+use crate::Discard;
+
+pub trait OnceLockExt<T> {
+    fn get_or_set(&self, value: T) -> &T;
+
+    fn set_or_discard(&self, value: T);
+}
+
+impl<T> OnceLockExt<T> for OnceLock<T> {
+    /// Sets a [`OnceLock`] to `value` if it's empty, then returns a reference
+    /// to whatever it holds.
+    ///
+    /// If another thread sets it first, `value` is dropped and the winner's
+    /// value is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::OnceLock;
+    ///
+    /// use treats::OnceLockExt;
+    ///
+    /// static NAME: OnceLock<&str> = OnceLock::new();
+    ///
+    /// assert_eq!(*NAME.get_or_set("treats"), "treats");
+    /// assert_eq!(*NAME.get_or_set("ignored"), "treats");
+    /// ```
+    #[inline]
+    fn get_or_set(&self, value: T) -> &T { self.get_or_init(|| value) }
+
+    /// Sets a [`OnceLock`] to `value` if it's empty, discarding `value`
+    /// otherwise.
+    ///
+    /// This is `lock.set(value).discard()`, the pattern shown for
+    /// [`Discard::discard`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::OnceLock;
+    ///
+    /// use treats::OnceLockExt;
+    ///
+    /// static FLAG_FORCE: OnceLock<bool> = OnceLock::new();
+    ///
+    /// fn parse_args() {
+    ///     for arg in std::env::args().skip(1) {
+    ///         if arg == "--force" {
+    ///             FLAG_FORCE.set_or_discard(true);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn set_or_discard(&self, value: T) { self.set(value).discard() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+    use std::thread;
+
+    #[test]
+    fn get_or_set() {
+        let lock = OnceLock::new();
+
+        assert_eq!(*lock.get_or_set(1), 1);
+        assert_eq!(*lock.get_or_set(2), 1);
+        assert_eq!(lock.get(), Some(&1));
+    }
+
+    #[test]
+    fn set_or_discard() {
+        let lock = OnceLock::new();
+
+        lock.set_or_discard("first");
+        lock.set_or_discard("second");
+        assert_eq!(lock.get(), Some(&"first"));
+    }
+
+    #[test]
+    fn get_or_set_threads() {
+        let lock = OnceLock::new();
+
+        let seen = Mutex::new(Vec::new());
+
+        thread::scope(|s| {
+            for i in 0..8 {
+                let (lock, seen) = (&lock, &seen);
+                s.spawn(move || seen.lock().unwrap().push(*lock.get_or_set(i)));
+            }
+        });
+
+        // Every thread gets the same winner back, whichever one it is
+        let winner = *lock.get().unwrap();
+        let seen = seen.into_inner().unwrap();
+        assert_eq!(seen.len(), 8);
+        assert!(seen.iter().all(|&n| n == winner));
+    }
+}
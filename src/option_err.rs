@@ -0,0 +1,74 @@
+//! The [`OptionErr`] convenience trait for [`Option`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+pub trait OptionErr<T> {
+    fn none_err<E, F: FnOnce() -> E>(self, f: F) -> Result<T, E>;
+}
+
+impl<T> OptionErr<T> for Option<T> {
+    /// Turns [`None`] into an error computed by `f`.
+    ///
+    /// This is equivalent to [`Option::ok_or_else`], but reads like
+    /// `InspectNone::inspect_none` from the `inspect_none` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use treats::OptionErr;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum ConfigError {
+    ///     Missing(&'static str),
+    /// }
+    ///
+    /// fn editor(config: &HashMap<&str, &str>) -> Result<String, ConfigError> {
+    ///     let editor = config
+    ///         .get("editor")
+    ///         .none_err(|| ConfigError::Missing("editor"))?;
+    ///
+    ///     Ok(editor.to_string())
+    /// }
+    ///
+    /// assert_eq!(editor(&HashMap::new()), Err(ConfigError::Missing("editor")));
+    /// ```
+    #[inline]
+    fn none_err<E, F: FnOnce() -> E>(self, f: F) -> Result<T, E> {
+        match self {
+            | Some(t) => Ok(t),
+            | None => Err(f()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn none_err_some() {
+        let mut called = false;
+        let optional_number: Option<u8> = Some(42);
+
+        assert_eq! {
+            optional_number.none_err(|| {
+                called = true;
+                "no number"
+            }),
+            Ok(42)
+        };
+        assert!(!called);
+    }
+
+    #[test]
+    fn none_err_none() {
+        let optional_number: Option<u8> = None;
+
+        assert_eq! {
+            optional_number.none_err(|| "no number"),
+            Err("no number")
+        };
+    }
+}
@@ -0,0 +1,489 @@
+//! Convenience traits for [`Option`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+use core::fmt::Debug;
+
+pub trait OptionExt<T> {
+    fn expect_none(self, msg: &str)
+    where
+        T: Debug;
+
+    fn try_inspect_none<E, F: FnOnce() -> Result<(), E>>(self, f: F) -> Result<Self, E>
+    where
+        Self: Sized;
+
+    fn get_or_init<F: FnOnce() -> T>(&mut self, f: F) -> &mut T;
+
+    fn get_or_default(&mut self) -> &mut T
+    where
+        T: Default;
+
+    fn filter_map<U, F: FnOnce(&T) -> Option<U>>(self, f: F) -> Option<U>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    /// Panics with `msg` and the contained value if [`Some`].
+    ///
+    /// This is the inverse of [`Option::expect`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is [`Some`], with a message including the
+    /// [`Debug`] representation of the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use treats::OptionExt;
+    ///
+    /// let mut users = HashMap::new();
+    /// users.insert(1, "tox").expect_none("user 1 already existed");
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn expect_none(self, msg: &str)
+    where
+        T: Debug,
+    {
+        if let Some(t) = self {
+            panic!("{msg}: {t:?}");
+        }
+    }
+
+    /// Calls a fallible function if [`None`].
+    ///
+    /// Returns the original option, or the error from `f` if it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Write};
+    ///
+    /// use treats::OptionExt;
+    ///
+    /// fn find_user(users: &[&str], name: &str, log: &mut impl Write) -> io::Result<Option<usize>> {
+    ///     users
+    ///         .iter()
+    ///         .position(|u| *u == name)
+    ///         .try_inspect_none(|| writeln!(log, "No user named {name}"))
+    /// }
+    /// ```
+    #[inline]
+    fn try_inspect_none<E, F: FnOnce() -> Result<(), E>>(self, f: F) -> Result<Self, E> {
+        if self.is_none() {
+            f()?;
+        }
+
+        Ok(self)
+    }
+
+    /// Inserts the value computed by `f` if [`None`], then returns a mutable
+    /// reference to the contained value.
+    ///
+    /// This is [`Option::get_or_insert_with`], named for lazily initialized
+    /// fields. `f` is only called if [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionExt;
+    ///
+    /// struct Index {
+    ///     words: Option<Vec<String>>,
+    /// }
+    ///
+    /// impl Index {
+    ///     fn words(&mut self) -> &mut Vec<String> {
+    ///         self.words.get_or_init(|| vec!["treats".to_string()])
+    ///     }
+    /// }
+    ///
+    /// let mut index = Index { words: None };
+    /// index.words().push("tox".to_string());
+    ///
+    /// assert_eq!(index.words(), &["treats", "tox"]);
+    /// ```
+    #[inline]
+    fn get_or_init<F: FnOnce() -> T>(&mut self, f: F) -> &mut T { self.get_or_insert_with(f) }
+
+    /// Inserts [`T::default()`](Default::default) if [`None`], then returns a
+    /// mutable reference to the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionExt;
+    ///
+    /// let mut hits: Option<u32> = None;
+    /// *hits.get_or_default() += 1;
+    /// *hits.get_or_default() += 1;
+    ///
+    /// assert_eq!(hits, Some(2));
+    /// ```
+    #[inline]
+    fn get_or_default(&mut self) -> &mut T
+    where
+        T: Default,
+    {
+        self.get_or_insert_with(T::default)
+    }
+
+    /// Calls `f` with a reference to the contained value if [`Some`], yielding
+    /// its result.
+    ///
+    /// Unlike [`Option::and_then`], `f` only borrows the value, so it can
+    /// inspect it and derive something new without taking ownership.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionExt;
+    ///
+    /// let name = Some(String::from("tox wtf"));
+    /// let last_name_len = name.filter_map(|n| n.split_once(' ').map(|(_, last)| last.len()));
+    ///
+    /// assert_eq!(last_name_len, Some(3));
+    /// ```
+    #[inline]
+    fn filter_map<U, F: FnOnce(&T) -> Option<U>>(self, f: F) -> Option<U> {
+        self.as_ref().and_then(f)
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait OptionReportExt<T> {
+    fn or_else_logged<F: FnOnce() -> Option<T>>(self, msg: &str, f: F) -> Option<T>;
+
+    fn expect_or_exit(self, msg: &str) -> T;
+}
+
+/// Formats the message [`OptionReportExt::expect_or_exit`] prints before
+/// exiting, which is `msg` as is.
+#[cfg(feature = "std")]
+fn report_missing(msg: &str) -> String { msg.to_owned() }
+
+#[cfg(feature = "std")]
+impl<T> OptionReportExt<T> for Option<T> {
+    /// Prints `msg` to stderr and calls `f` if [`None`].
+    ///
+    /// Returns the original option if [`Some`]. This fuses
+    /// `InspectNone::inspect_none` from the `inspect_none` feature and
+    /// [`Option::or_else`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionReportExt;
+    ///
+    /// fn editor() -> Option<String> {
+    ///     std::env::var("VISUAL")
+    ///         .ok()
+    ///         .or_else_logged("VISUAL is unset, trying EDITOR", || std::env::var("EDITOR").ok())
+    /// }
+    /// ```
+    #[inline]
+    fn or_else_logged<F: FnOnce() -> Self>(self, msg: &str, f: F) -> Self {
+        if self.is_none() {
+            eprintln!("{msg}");
+            return f();
+        }
+
+        self
+    }
+
+    /// Yields the [`Some`] value, or prints `msg` to stderr and exits with code
+    /// 1 if [`None`].
+    ///
+    /// This is the [`Option`] counterpart to
+    /// `ResultReportExt::unwrap_or_report` from the `result_ext` feature,
+    /// for required values at the top of a CLI's `main`, where a panic and its
+    /// backtrace would be noise. Since it calls [`std::process::exit`],
+    /// destructors don't run, so flush anything that needs it first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use treats::OptionReportExt;
+    ///
+    /// let home = std::env::var_os("HOME").expect_or_exit("HOME must be set");
+    /// ```
+    fn expect_or_exit(self, msg: &str) -> T {
+        self.unwrap_or_else(|| {
+            eprintln!("{}", report_missing(msg));
+            std::process::exit(1)
+        })
+    }
+}
+
+pub trait OptionRefExt<T> {
+    #[must_use]
+    fn cloned_or(self, default: T) -> T
+    where
+        T: Clone;
+
+    #[must_use]
+    fn cloned_or_default(self) -> T
+    where
+        T: Clone + Default;
+}
+
+impl<T> OptionRefExt<T> for Option<&T> {
+    /// Clones the referenced value if [`Some`], or yields `default` if
+    /// [`None`].
+    ///
+    /// This is `.cloned().unwrap_or(default)`. Only the [`Some`] case clones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use treats::OptionRefExt;
+    ///
+    /// let aliases = HashMap::from([("ll", String::from("ls -l"))]);
+    ///
+    /// assert_eq!(aliases.get("ll").cloned_or(String::from("ll")), "ls -l");
+    /// assert_eq!(aliases.get("la").cloned_or(String::from("la")), "la");
+    /// ```
+    #[inline]
+    fn cloned_or(self, default: T) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            | Some(t) => t.clone(),
+            | None => default,
+        }
+    }
+
+    /// Clones the referenced value if [`Some`], or yields
+    /// [`T::default()`](Default::default) if [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionRefExt;
+    ///
+    /// let args = ["treats", "--verbose"];
+    ///
+    /// assert_eq!(args.get(1).cloned_or_default(), "--verbose");
+    /// assert_eq!(args.get(2).cloned_or_default(), "");
+    /// ```
+    #[inline]
+    fn cloned_or_default(self) -> T
+    where
+        T: Clone + Default,
+    {
+        self.map_or_else(T::default, T::clone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+
+    use core::cell::Cell;
+    use std::string::String;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn or_else_logged_some() {
+        let mut called = false;
+        let optional_number: Option<u8> = Some(42);
+        let n = optional_number.or_else_logged("no number", || {
+            called = true;
+            Some(0)
+        });
+        assert_eq!(n, Some(42));
+        assert!(!called);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn or_else_logged_none() {
+        let mut called = false;
+        let optional_number: Option<u8> = None;
+        let n = optional_number.or_else_logged("no number", || {
+            called = true;
+            Some(0)
+        });
+        assert_eq!(n, Some(0));
+        assert!(called);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn expect_or_exit_some() {
+        let optional_number: Option<u8> = Some(42);
+
+        assert_eq!(optional_number.expect_or_exit("unreachable"), 42);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report_missing_format() {
+        assert_eq!(report_missing("HOME must be set"), "HOME must be set");
+        assert_eq!(report_missing(""), "");
+    }
+
+    #[test]
+    fn expect_none_none() {
+        let optional_number: Option<u8> = None;
+        optional_number.expect_none("unexpected number");
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected number: 42")]
+    fn expect_none_some() {
+        let optional_number: Option<u8> = Some(42);
+        optional_number.expect_none("unexpected number");
+    }
+
+    #[test]
+    fn try_inspect_none_some() {
+        let mut called = false;
+        let optional_number: Option<u8> = Some(42);
+        let n = optional_number.try_inspect_none(|| {
+            called = true;
+            Err("failed")
+        });
+        assert_eq!(n, Ok(Some(42)));
+        assert!(!called);
+    }
+
+    #[test]
+    fn try_inspect_none_none() {
+        let mut called = false;
+        let optional_number: Option<u8> = None;
+        let n = optional_number.try_inspect_none(|| {
+            called = true;
+            Ok::<_, &str>(())
+        });
+        assert_eq!(n, Ok(None));
+        assert!(called);
+    }
+
+    #[test]
+    fn try_inspect_none_none_and_fail() {
+        let optional_number: Option<u8> = None;
+        let n = optional_number.try_inspect_none(|| Err("failed"));
+        assert_eq!(n, Err("failed"));
+    }
+
+    #[test]
+    fn get_or_init_once() {
+        let mut calls = 0;
+        let mut cache: Option<u8> = None;
+
+        let mut init = || {
+            calls += 1;
+            42
+        };
+
+        assert_eq!(*cache.get_or_init(&mut init), 42);
+        assert_eq!(*cache.get_or_init(&mut init), 42);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn get_or_init_some() {
+        let mut called = false;
+        let mut cache = Some(7);
+
+        *cache.get_or_init(|| {
+            called = true;
+            0
+        }) += 1;
+        assert_eq!(cache, Some(8));
+        assert!(!called);
+    }
+
+    #[test]
+    fn get_or_default() {
+        let mut cache: Option<u8> = None;
+
+        assert_eq!(*cache.get_or_default(), 0);
+        *cache.get_or_default() = 42;
+        assert_eq!(*cache.get_or_default(), 42);
+    }
+
+    #[test]
+    fn filter_map_some() {
+        let word = Some("treats");
+
+        assert_eq!(word.filter_map(|w| w.find('e')), Some(2));
+        assert_eq!(word.filter_map(|w| w.find('z')), None);
+    }
+
+    #[test]
+    fn filter_map_borrows() {
+        let mut seen = None;
+        let numbers = Some([1, 2, 3]);
+
+        let first_even = numbers.filter_map(|n| {
+            seen = Some(n.len());
+            n.iter().copied().find(|n| n % 2 == 0)
+        });
+        assert_eq!(first_even, Some(2));
+        assert_eq!(seen, Some(3));
+    }
+
+    #[test]
+    fn filter_map_none() {
+        let mut called = false;
+        let word: Option<&str> = None;
+
+        let n = word.filter_map(|w| {
+            called = true;
+            Some(w.len())
+        });
+        assert_eq!(n, None);
+        assert!(!called);
+    }
+
+    #[test]
+    fn cloned_or_some() {
+        let name = String::from("treats");
+
+        assert_eq!(Some(&name).cloned_or(String::from("tox")), "treats");
+        assert_eq!(Some(&name).cloned_or_default(), "treats");
+    }
+
+    #[test]
+    fn cloned_or_none() {
+        let name: Option<&String> = None;
+
+        assert_eq!(name.cloned_or(String::from("tox")), "tox");
+        assert_eq!(name.cloned_or_default(), "");
+    }
+
+    #[derive(Default)]
+    struct CountClones<'a>(Option<&'a Cell<usize>>);
+
+    impl Clone for CountClones<'_> {
+        fn clone(&self) -> Self {
+            if let Some(clones) = self.0 {
+                clones.set(clones.get() + 1);
+            }
+            Self(self.0)
+        }
+    }
+
+    #[test]
+    fn cloned_or_clones_only_some() {
+        let clones = Cell::new(0);
+        let value = CountClones(Some(&clones));
+
+        let _ = None.cloned_or(CountClones(Some(&clones)));
+        let _ = None::<&CountClones>.cloned_or_default();
+        assert_eq!(clones.get(), 0);
+
+        let _ = Some(&value).cloned_or(CountClones(None));
+        let _ = Some(&value).cloned_or_default();
+        assert_eq!(clones.get(), 2);
+    }
+}
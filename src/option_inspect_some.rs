@@ -0,0 +1,86 @@
+//! The [`InspectSome`] convenience trait for [`Option`]s
+
+pub trait InspectSome<T> {
+    #[must_use]
+    fn inspect_some<F: FnOnce(&T)>(self, f: F) -> Self;
+}
+
+impl<T> InspectSome<T> for Option<T> {
+    /// Calls a function with a reference to the contained value if [`Some`].
+    ///
+    /// Returns the original option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::InspectSome;
+    ///
+    /// fn find_user(users: &[&str], prefix: &str) -> Option<usize> {
+    ///     users
+    ///         .iter()
+    ///         .position(|u| u.starts_with(prefix))
+    ///         .inspect_some(|i| eprintln!("Found user at index {i}"))
+    /// }
+    /// ```
+    #[inline]
+    fn inspect_some<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Some(ref t) = self {
+            f(t);
+        }
+
+        self
+    }
+}
+
+impl<T> InspectSome<T> for &Option<T> {
+    /// Calls a function with a reference to the contained value if [`Some`].
+    ///
+    /// Returns the original option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::InspectSome;
+    ///
+    /// fn find_user(users: &[&str], prefix: &str) -> Option<usize> {
+    ///     users
+    ///         .iter()
+    ///         .position(|u| u.starts_with(prefix))
+    ///         .inspect_some(|i| eprintln!("Found user at index {i}"))
+    /// }
+    /// ```
+    #[inline]
+    fn inspect_some<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Some(t) = self {
+            f(t);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_inspect_some() {
+        let mut inspected = None;
+        let optional_number: Option<u8> = Some(42);
+        let _ = optional_number.inspect_some(|n| inspected = Some(*n));
+        assert_eq!(inspected, Some(42));
+
+        let mut inspected = None;
+        let optional_number: Option<u8> = None;
+        let _ = optional_number.inspect_some(|n| inspected = Some(*n));
+        assert_eq!(inspected, None);
+    }
+
+    #[test]
+    fn option_ref_inspect_some() {
+        let mut inspected = None;
+        let optional_name = Some("tox");
+        let _ = (&optional_name).inspect_some(|n| inspected = Some(n.len()));
+        assert_eq!(inspected, Some(3));
+        assert_eq!(optional_name, Some("tox"));
+    }
+}
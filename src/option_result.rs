@@ -0,0 +1,62 @@
+//! The [`OptionResultExt`] convenience trait for [`Option`]s of [`Result`]s
+
+pub trait OptionResultExt<T, E> {
+    fn ok_flatten(self) -> Option<T>;
+
+    fn err_flatten(self) -> Option<E>;
+}
+
+impl<T, E> OptionResultExt<T, E> for Option<Result<T, E>> {
+    /// Yields the value of `Some(Ok(t))`, or [`None`] otherwise.
+    ///
+    /// This is [`Option::transpose`] followed by [`Result::ok`] and
+    /// [`Option::flatten`], discarding any error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionResultExt;
+    ///
+    /// let args = ["--jobs", "4"];
+    /// let jobs = args.get(1).map(|a| a.parse::<u8>()).ok_flatten();
+    ///
+    /// assert_eq!(jobs, Some(4));
+    /// ```
+    #[inline]
+    fn ok_flatten(self) -> Option<T> { self.and_then(Result::ok) }
+
+    /// Yields the error of `Some(Err(e))`, or [`None`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionResultExt;
+    ///
+    /// let port = Some("http".parse::<u16>());
+    ///
+    /// assert!(port.err_flatten().is_some());
+    /// ```
+    #[inline]
+    fn err_flatten(self) -> Option<E> { self.and_then(Result::err) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ok_flatten() {
+        assert_eq!(Some(Ok::<u8, &str>(42)).ok_flatten(), Some(42));
+        assert_eq!(Some(Err::<u8, &str>("failed")).ok_flatten(), None);
+        assert_eq!(None::<Result<u8, &str>>.ok_flatten(), None);
+        assert_eq!(Some(Ok::<Option<u8>, &str>(None)).ok_flatten(), Some(None));
+    }
+
+    #[test]
+    fn err_flatten() {
+        assert_eq!(Some(Ok::<u8, &str>(42)).err_flatten(), None);
+        assert_eq!(Some(Err::<u8, &str>("failed")).err_flatten(), Some("failed"));
+        assert_eq!(None::<Result<u8, &str>>.err_flatten(), None);
+        assert_eq!(Some(Err::<u8, Option<&str>>(None)).err_flatten(), Some(None));
+    }
+}
@@ -0,0 +1,67 @@
+//! The [`OptionZipExt`] convenience trait for [`Option`]s
+
+pub trait OptionZipExt<T> {
+    fn zip_with<U, R, F>(self, other: Option<U>, f: F) -> Option<R>
+    where
+        F: FnOnce(T, U) -> R;
+}
+
+impl<T> OptionZipExt<T> for Option<T> {
+    /// Combines two options with `f` if both are [`Some`].
+    ///
+    /// This is equivalent to `self.zip(other).map(|(a, b)| f(a, b))`. `f` is
+    /// only called if both options are [`Some`].
+    ///
+    /// The standard library has an unstable `Option::zip_with` with the same
+    /// behavior, so calling this as a method triggers the
+    /// `unstable_name_collisions` lint. Fully qualified syntax avoids it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OptionZipExt;
+    ///
+    /// fn area(width: Option<u32>, height: Option<u32>) -> Option<u32> {
+    ///     OptionZipExt::zip_with(width, height, |w, h| w * h)
+    /// }
+    ///
+    /// assert_eq!(area(Some(3), Some(4)), Some(12));
+    /// assert_eq!(area(Some(3), None), None);
+    /// ```
+    #[inline]
+    fn zip_with<U, R, F>(self, other: Option<U>, f: F) -> Option<R>
+    where
+        F: FnOnce(T, U) -> R,
+    {
+        match (self, other) {
+            | (Some(a), Some(b)) => Some(f(a, b)),
+            | _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zip_with_both_some() {
+        assert_eq!(OptionZipExt::zip_with(Some(2), Some(3), |a, b| a + b), Some(5));
+        assert_eq!(OptionZipExt::zip_with(Some("treats"), Some(2), |s, n| s.len() * n), Some(12));
+    }
+
+    #[test]
+    fn zip_with_any_none() {
+        let mut called = false;
+
+        let mut add = |a: u8, b: u8| {
+            called = true;
+            a + b
+        };
+
+        assert_eq!(OptionZipExt::zip_with(None, Some(3), &mut add), None);
+        assert_eq!(OptionZipExt::zip_with(Some(2), None, &mut add), None);
+        assert_eq!(OptionZipExt::zip_with(None, None, &mut add), None);
+        assert!(!called);
+    }
+}
@@ -0,0 +1,234 @@
+//! Convenience traits for [`OsStr`] and [`OsString`]s
+
+use std::ffi::{OsStr, OsString};
+
+use crate::PathToString;
+
+pub trait OsStrSplitExt {
+    fn split_lossy(&self, sep: char) -> Vec<String>;
+
+    fn split_strict(&self, sep: char) -> Option<Vec<String>>;
+}
+
+impl OsStrSplitExt for OsStr {
+    /// Converts an [`OsStr`] into a lossy [`String`] and splits it on `sep`.
+    ///
+    /// Like [`str::split`], consecutive separators produce empty strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::OsStrSplitExt;
+    ///
+    /// fn path_dirs() -> Vec<String> {
+    ///     std::env::var_os("PATH")
+    ///         .map(|path| path.split_lossy(':'))
+    ///         .unwrap_or_default()
+    /// }
+    /// ```
+    #[inline]
+    fn split_lossy(&self, sep: char) -> Vec<String> {
+        self.to_lossy_string().split(sep).map(ToString::to_string).collect()
+    }
+
+    /// Splits an [`OsStr`] on `sep` if it's valid unicode.
+    #[inline]
+    fn split_strict(&self, sep: char) -> Option<Vec<String>> {
+        self.to_str().map(|s| s.split(sep).map(ToString::to_string).collect())
+    }
+}
+
+impl OsStrSplitExt for OsString {
+    /// Converts an [`OsString`] into a lossy [`String`] and splits it on `sep`.
+    ///
+    /// Like [`str::split`], consecutive separators produce empty strings.
+    #[inline]
+    fn split_lossy(&self, sep: char) -> Vec<String> { self.as_os_str().split_lossy(sep) }
+
+    /// Splits an [`OsString`] on `sep` if it's valid unicode.
+    #[inline]
+    fn split_strict(&self, sep: char) -> Option<Vec<String>> { self.as_os_str().split_strict(sep) }
+}
+
+pub trait OsStringExt {
+    #[must_use]
+    fn pushed<S: AsRef<OsStr>>(self, s: S) -> Self;
+
+    #[must_use]
+    fn joined<I, S>(self, parts: I, sep: &str) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>;
+}
+
+impl OsStringExt for OsString {
+    /// Appends `s` to an [`OsString`], returning it for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use treats::OsStringExt;
+    ///
+    /// let arg = OsString::from("--config=").pushed("/etc/treats.toml");
+    /// assert_eq!(arg, "--config=/etc/treats.toml");
+    /// ```
+    #[inline]
+    fn pushed<S: AsRef<OsStr>>(mut self, s: S) -> Self {
+        self.push(s);
+        self
+    }
+
+    /// Appends `parts` to an [`OsString`], separated by `sep`.
+    ///
+    /// The [`OsString`] acts as a prefix, so `sep` only goes between parts,
+    /// and an empty `parts` leaves it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use treats::OsStringExt;
+    ///
+    /// let arg = OsString::from("--features=").joined(["std", "serde"], ",");
+    /// assert_eq!(arg, "--features=std,serde");
+    /// ```
+    fn joined<I, S>(mut self, parts: I, sep: &str) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        for (i, part) in parts.into_iter().enumerate() {
+            if i > 0 {
+                self.push(sep);
+            }
+            self.push(part);
+        }
+        self
+    }
+}
+
+pub trait OsStrCaseExt {
+    fn eq_ignore_ascii_case_os(&self, other: &OsStr) -> bool;
+}
+
+impl OsStrCaseExt for OsStr {
+    /// Checks whether two [`OsStr`]s are equal, ignoring ASCII case.
+    ///
+    /// Only `A` to `Z` match `a` to `z`, like with [`str::eq_ignore_ascii_case`],
+    /// so non-ASCII letters like `É` and `é` still differ. Both sides are
+    /// compared as lossy [`String`]s, so invalid unicode that differs only in
+    /// the bytes replaced with `U+FFFD` compares equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use treats::OsStrCaseExt;
+    ///
+    /// fn is_readme(name: &OsStr) -> bool { name.eq_ignore_ascii_case_os(OsStr::new("readme.md")) }
+    ///
+    /// assert!(is_readme(OsStr::new("README.md")));
+    /// ```
+    #[inline]
+    fn eq_ignore_ascii_case_os(&self, other: &OsStr) -> bool {
+        self.to_lossy_string().eq_ignore_ascii_case(&other.to_lossy_string())
+    }
+}
+
+impl OsStrCaseExt for OsString {
+    /// Checks whether two [`OsStr`]s are equal, ignoring ASCII case.
+    ///
+    /// Only ASCII letters are folded, and invalid unicode is compared lossily.
+    #[inline]
+    fn eq_ignore_ascii_case_os(&self, other: &OsStr) -> bool {
+        self.as_os_str().eq_ignore_ascii_case_os(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_path() {
+        let path = OsString::from("/usr/bin::/bin:");
+        let expected = ["/usr/bin", "", "/bin", ""];
+
+        assert_eq!(path.split_lossy(':'), expected);
+        assert_eq!(path.split_strict(':').unwrap(), expected);
+        assert_eq!(OsStr::new("").split_lossy(':'), [""]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn split_invalid_unicode() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = OsStr::from_bytes(b"/usr/bin:/\xffbin");
+
+        assert_eq!(path.split_lossy(':'), ["/usr/bin", "/\u{FFFD}bin"]);
+        assert_eq!(path.split_strict(':'), None);
+    }
+
+    #[test]
+    fn pushed() {
+        let s = OsString::new().pushed("a").pushed(OsStr::new("b")).pushed(String::from("c"));
+
+        assert_eq!(s, "abc");
+    }
+
+    #[test]
+    fn joined() {
+        let s = OsString::from("PATH=").joined(["/usr/bin", "/bin", "/sbin"], ":");
+
+        assert_eq!(s.as_encoded_bytes(), b"PATH=/usr/bin:/bin:/sbin");
+        assert_eq!(OsString::new().joined(["a"], ":"), "a");
+    }
+
+    #[test]
+    fn joined_empty() {
+        assert_eq!(OsString::from("PATH=").joined([] as [&str; 0], ":"), "PATH=");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn joined_invalid_unicode() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let parts = [OsStr::from_bytes(b"\xffa"), OsStr::new("b")];
+        let s = OsString::from("--files=").joined(parts, ",");
+
+        assert_eq!(s.as_bytes(), b"--files=\xffa,b");
+    }
+
+    #[test]
+    fn eq_ignore_ascii_case_os() {
+        let name = OsStr::new("File.TXT");
+
+        assert!(name.eq_ignore_ascii_case_os(OsStr::new("file.txt")));
+        assert!(OsString::from("file.txt").eq_ignore_ascii_case_os(name));
+        assert!(!name.eq_ignore_ascii_case_os(OsStr::new("file.txt.bak")));
+        assert!(OsStr::new("").eq_ignore_ascii_case_os(OsStr::new("")));
+    }
+
+    #[test]
+    fn eq_ignore_ascii_case_os_non_ascii() {
+        assert!(!OsStr::new("CAFÉ").eq_ignore_ascii_case_os(OsStr::new("café")));
+        assert!(OsStr::new("CAFé").eq_ignore_ascii_case_os(OsStr::new("café")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn eq_ignore_ascii_case_os_invalid_unicode() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let a = OsStr::from_bytes(b"A\xff");
+        let b = OsStr::from_bytes(b"a\xfe");
+
+        assert!(a.eq_ignore_ascii_case_os(b));
+    }
+}
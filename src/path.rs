@@ -1,12 +1,80 @@
 //! Convenience traits for [`Path`] and [`PathBuf`]s
 
+use std::borrow::Cow;
 use std::ffi::{OsStr, OsString};
-use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
+use std::path::{MAIN_SEPARATOR, MAIN_SEPARATOR_STR, Path, PathBuf, is_separator};
 
 pub trait PathToString {
     fn to_lossy_string(&self) -> String;
 
     fn to_string(&self) -> Option<String>;
+
+    /// Yields a [`String`] if valid unicode, or `default` otherwise.
+    ///
+    /// This avoids both the replacement characters of
+    /// [`to_lossy_string`](PathToString::to_lossy_string) and unwrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathToString;
+    ///
+    /// fn log_open(path: &Path) { eprintln!("Opening {}", path.to_string_or("<non-utf8 path>")) }
+    /// ```
+    #[inline]
+    fn to_string_or(&self, default: &str) -> String {
+        self.to_string().unwrap_or_else(|| default.into())
+    }
+
+    /// Converts into a lossy [`String`] using `/` as the separator.
+    ///
+    /// Only [`MAIN_SEPARATOR`] is converted, so on unix this is the same as
+    /// [`to_lossy_string`](PathToString::to_lossy_string), and a `\` in a file
+    /// name is kept. On Windows, `C:\a` becomes `C:/a`, and a UNC path like
+    /// `\\server\share` becomes `//server/share`. Verbatim paths starting with
+    /// `\\?\` don't accept `/`, so they won't round trip through
+    /// [`from_slash`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathToString;
+    ///
+    /// let path = Path::new("assets").join("icons").join("treat.png");
+    ///
+    /// assert_eq!(path.to_slash_string(), "assets/icons/treat.png");
+    /// ```
+    #[inline]
+    fn to_slash_string(&self) -> String { self.to_lossy_string().replace(MAIN_SEPARATOR, "/") }
+
+    /// Converts into a lossy [`String`] quoted for a POSIX shell.
+    ///
+    /// The whole string is wrapped in single quotes, and any single quote in
+    /// it is written as `'\''`, so the result can be spliced into a `sh -c`
+    /// command as a single word. An empty string becomes `''`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathToString;
+    ///
+    /// let path = Path::new("/tmp/tox's treats");
+    /// let cmd = format!("ls -l {}", path.to_shell_string());
+    ///
+    /// assert_eq!(cmd, r"ls -l '/tmp/tox'\''s treats'");
+    /// ```
+    #[inline]
+    fn to_shell_string(&self) -> String {
+        format!("'{}'", self.to_lossy_string().replace('\'', r"'\''"))
+    }
 }
 
 impl PathToString for Path {
@@ -101,9 +169,373 @@ impl PathToString for OsString {
     fn to_string(&self) -> Option<String> { self.to_str().map(ToString::to_string) }
 }
 
+impl PathToString for Cow<'_, Path> {
+    /// Converts a [`Cow<Path>`] into a lossy [`String`].
+    #[inline]
+    fn to_lossy_string(&self) -> String { (**self).to_lossy_string() }
+
+    /// Yields a [`String`] if the [`Cow<Path>`] is valid unicode.
+    #[inline]
+    fn to_string(&self) -> Option<String> { (**self).to_string() }
+}
+
+impl PathToString for Cow<'_, OsStr> {
+    /// Converts a [`Cow<OsStr>`] into a lossy [`String`].
+    #[inline]
+    fn to_lossy_string(&self) -> String { (**self).to_lossy_string() }
+
+    /// Yields a [`String`] if the [`Cow<OsStr>`] is valid unicode.
+    #[inline]
+    fn to_string(&self) -> Option<String> { (**self).to_string() }
+}
+
+pub trait PathsToStrings {
+    fn to_lossy_strings(&self) -> Vec<String>;
+
+    fn to_strings(&self) -> Option<Vec<String>>;
+}
+
+impl PathsToStrings for [PathBuf] {
+    /// Converts each [`PathBuf`] into a lossy [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use treats::PathsToStrings;
+    ///
+    /// fn args(paths: &[PathBuf]) -> Vec<String> { paths.to_lossy_strings() }
+    /// ```
+    #[inline]
+    fn to_lossy_strings(&self) -> Vec<String> {
+        self.iter().map(PathToString::to_lossy_string).collect()
+    }
+
+    /// Yields a [`String`] for each [`PathBuf`] if all of them are valid
+    /// unicode.
+    #[inline]
+    fn to_strings(&self) -> Option<Vec<String>> {
+        self.iter().map(PathToString::to_string).collect()
+    }
+}
+
+impl PathsToStrings for Vec<PathBuf> {
+    /// Converts each [`PathBuf`] into a lossy [`String`].
+    #[inline]
+    fn to_lossy_strings(&self) -> Vec<String> { self.as_slice().to_lossy_strings() }
+
+    /// Yields a [`String`] for each [`PathBuf`] if all of them are valid
+    /// unicode.
+    #[inline]
+    fn to_strings(&self) -> Option<Vec<String>> { self.as_slice().to_strings() }
+}
+
+pub trait PathToDirString {
+    fn to_dir_string(&self) -> String;
+}
+
+impl PathToDirString for Path {
+    /// Converts a [`Path`] into a lossy [`String`] ending with a separator.
+    ///
+    /// [`MAIN_SEPARATOR`] is only appended if the [`Path`] doesn't already end
+    /// with a separator, which on Windows includes `/`. An empty [`Path`]
+    /// yields just the separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathToDirString;
+    ///
+    /// assert_eq!(Path::new("/a/b").to_dir_string(), "/a/b/");
+    /// assert_eq!(Path::new("/a/b/").to_dir_string(), "/a/b/");
+    /// ```
+    #[inline]
+    fn to_dir_string(&self) -> String {
+        let mut s = self.to_lossy_string();
+        if !s.ends_with(is_separator) {
+            s.push(MAIN_SEPARATOR);
+        }
+        s
+    }
+}
+
+impl PathToDirString for PathBuf {
+    /// Converts a [`PathBuf`] into a lossy [`String`] ending with a separator.
+    ///
+    /// An empty [`PathBuf`] yields just the separator.
+    #[inline]
+    fn to_dir_string(&self) -> String { self.as_path().to_dir_string() }
+}
+
+#[cfg(unix)]
+pub trait BytesToString {
+    fn to_lossy_string(&self) -> String;
+
+    fn to_string(&self) -> Option<String>;
+}
+
+#[cfg(unix)]
+impl BytesToString for [u8] {
+    /// Converts raw path bytes into a lossy [`String`].
+    ///
+    /// Invalid unicode is replaced with [`U+FFFD`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::BytesToString;
+    ///
+    /// let path = c"/path/to/whatever";
+    /// assert_eq!(path.to_bytes().to_lossy_string(), "/path/to/whatever");
+    /// ```
+    #[inline]
+    fn to_lossy_string(&self) -> String { OsStr::from_bytes(self).to_lossy_string() }
+
+    /// Yields a [`String`] if the path bytes are valid unicode.
+    #[inline]
+    fn to_string(&self) -> Option<String> { PathToString::to_string(OsStr::from_bytes(self)) }
+}
+
+pub trait PathNameExt {
+    fn file_stem_string(&self) -> Option<String>;
+
+    fn extension_string(&self) -> Option<String>;
+}
+
+impl PathNameExt for Path {
+    /// Yields the [`file_stem`](Path::file_stem) of a [`Path`] as a [`String`]
+    /// if it exists and is valid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathNameExt;
+    ///
+    /// assert_eq!(Path::new("/tmp/archive.tar.gz").file_stem_string().unwrap(), "archive.tar");
+    /// ```
+    #[inline]
+    fn file_stem_string(&self) -> Option<String> {
+        self.file_stem().and_then(PathToString::to_string)
+    }
+
+    /// Yields the [`extension`](Path::extension) of a [`Path`] as a [`String`]
+    /// if it exists and is valid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathNameExt;
+    ///
+    /// assert_eq!(Path::new("/tmp/archive.tar.gz").extension_string().unwrap(), "gz");
+    /// ```
+    #[inline]
+    fn extension_string(&self) -> Option<String> {
+        self.extension().and_then(PathToString::to_string)
+    }
+}
+
+impl PathNameExt for PathBuf {
+    /// Yields the [`file_stem`](Path::file_stem) of a [`PathBuf`] as a
+    /// [`String`] if it exists and is valid unicode.
+    #[inline]
+    fn file_stem_string(&self) -> Option<String> { self.as_path().file_stem_string() }
+
+    /// Yields the [`extension`](Path::extension) of a [`PathBuf`] as a
+    /// [`String`] if it exists and is valid unicode.
+    #[inline]
+    fn extension_string(&self) -> Option<String> { self.as_path().extension_string() }
+}
+
+pub trait PathLenExt {
+    fn encoded_len(&self) -> usize;
+
+    fn char_len_lossy(&self) -> usize;
+}
+
+impl PathLenExt for Path {
+    /// Yields the length of a [`Path`] in bytes of its [`OsStr`] encoding,
+    /// without allocating.
+    ///
+    /// This is [`OsStr::len`]. On unix, that's the number of bytes passed to
+    /// the OS. On Windows, it's the length of the WTF-8 encoding Rust uses
+    /// internally, not the number of UTF-16 code units the OS sees, so only
+    /// rely on it for ASCII paths there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathLenExt;
+    ///
+    /// fn path_buffer(path: &Path) -> Vec<u8> { Vec::with_capacity(path.encoded_len() + 1) }
+    /// ```
+    #[inline]
+    fn encoded_len(&self) -> usize { self.as_os_str().len() }
+
+    /// Counts the [`char`]s of a [`Path`] converted into a lossy [`String`].
+    ///
+    /// Invalid unicode counts as the `U+FFFD`s it would be displayed as. This
+    /// only allocates for invalid unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathLenExt;
+    ///
+    /// let path = Path::new("/tmp/café");
+    ///
+    /// assert_eq!(path.char_len_lossy(), 9);
+    /// ```
+    #[inline]
+    fn char_len_lossy(&self) -> usize { self.to_string_lossy().chars().count() }
+}
+
+impl PathLenExt for PathBuf {
+    /// Yields the length of a [`PathBuf`] in bytes of its [`OsStr`] encoding,
+    /// without allocating.
+    #[inline]
+    fn encoded_len(&self) -> usize { self.as_path().encoded_len() }
+
+    /// Counts the [`char`]s of a [`PathBuf`] converted into a lossy [`String`].
+    #[inline]
+    fn char_len_lossy(&self) -> usize { self.as_path().char_len_lossy() }
+}
+
+/// Yields the first of `candidates` that [`exists`](Path::exists).
+///
+/// Candidates are checked in order, and non-unicode paths are checked like any
+/// other. Yields [`None`] if no candidate exists, including when there are
+/// none.
+///
+/// # Examples
+///
+/// ```
+/// use treats::first_existing;
+///
+/// let config = first_existing(["./treats.toml", "/etc/treats.toml", "/"]);
+/// assert!(config.is_some());
+/// ```
+pub fn first_existing<I, P>(candidates: I) -> Option<PathBuf>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<PathBuf>,
+{
+    candidates.into_iter().map(Into::into).find(|path| path.exists())
+}
+
+/// Converts a `/`-separated path into a [`PathBuf`] using [`MAIN_SEPARATOR`].
+///
+/// This is the inverse of [`PathToString::to_slash_string`]. On unix, the
+/// path is kept as is.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use treats::from_slash;
+///
+/// assert_eq!(from_slash("assets/icons/treat.png"), Path::new("assets").join("icons/treat.png"));
+/// ```
+#[must_use]
+pub fn from_slash(s: &str) -> PathBuf {
+    PathBuf::from(s.replace('/', MAIN_SEPARATOR_STR))
+}
+
+/// Joins path components with [`MAIN_SEPARATOR`] into a lossy [`String`].
+///
+/// This is the per-component counterpart to
+/// [`PathToString::to_lossy_string`]. Unlike [`Path::join`], components are
+/// kept verbatim, so one containing a separator, or an absolute one, doesn't
+/// replace what came before. No components yield an empty [`String`].
+///
+/// # Examples
+///
+/// ```
+/// use std::path::MAIN_SEPARATOR_STR;
+///
+/// use treats::components_to_lossy_string;
+///
+/// assert_eq!(components_to_lossy_string(["usr", "bin"]), ["usr", "bin"].join(MAIN_SEPARATOR_STR));
+/// ```
+#[must_use]
+pub fn components_to_lossy_string<I, P>(parts: I) -> String
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<OsStr>,
+{
+    let mut s = String::new();
+    for (i, part) in parts.into_iter().enumerate() {
+        if i > 0 {
+            s.push(MAIN_SEPARATOR);
+        }
+        s.push_str(&part.as_ref().to_string_lossy());
+    }
+    s
+}
+
+/// Device names Windows reserves regardless of extension.
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Turns `name` into something safe to use as a single filename.
+///
+/// Path separators, characters reserved on Windows (`:*?"<>|`), and control
+/// characters are replaced with `_`. Leading and trailing dots and spaces are
+/// trimmed, so `.` and `..` can't be produced. Names Windows reserves for
+/// devices, like `CON` or `nul.txt`, get a `_` after the stem. If nothing is
+/// left, `_` is returned instead.
+///
+/// # Examples
+///
+/// ```
+/// use treats::sanitize_filename;
+///
+/// assert_eq!(sanitize_filename("report: 2024/05"), "report_ 2024_05");
+/// assert_eq!(sanitize_filename("../../etc/passwd"), "_.._etc_passwd");
+/// assert_eq!(sanitize_filename("CON"), "CON_");
+/// assert_eq!(sanitize_filename("..."), "_");
+/// ```
+#[must_use]
+pub fn sanitize_filename(name: &str) -> String {
+    let replaced = name
+        .chars()
+        .map(|c| match c {
+            | '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            | c if c.is_control() => '_',
+            | c => c,
+        })
+        .collect::<String>();
+
+    let trimmed = replaced.trim_matches(|c| c == '.' || c == ' ');
+    if trimmed.is_empty() {
+        return "_".to_string();
+    }
+
+    let (stem, rest) = trimmed.split_at(trimmed.find('.').unwrap_or(trimmed.len()));
+    if RESERVED_NAMES.iter().any(|reserved| stem.eq_ignore_ascii_case(reserved)) {
+        format!("{stem}_{rest}")
+    } else {
+        trimmed.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::tempdir;
 
     #[test]
     fn path_to_string() {
@@ -126,4 +558,319 @@ mod tests {
         assert_eq!(path.to_lossy_string(), path_str);
         assert_eq!(path.to_string().unwrap(), path_str);
     }
+
+    #[test]
+    fn path_to_string_or() {
+        let path_str = "/path/to/whatever";
+
+        assert_eq!(Path::new(path_str).to_string_or("<invalid>"), path_str);
+        assert_eq!(PathBuf::from(path_str).to_string_or("<invalid>"), path_str);
+        assert_eq!(OsStr::new(path_str).to_string_or("<invalid>"), path_str);
+        assert_eq!(OsString::from(path_str).to_string_or("<invalid>"), path_str);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_to_string_or_invalid_unicode() {
+        let os_str = OsStr::from_bytes(b"/path/to/\xffwhatever");
+
+        assert_eq!(Path::new(os_str).to_string_or("<invalid>"), "<invalid>");
+        assert_eq!(PathBuf::from(os_str).to_string_or("<invalid>"), "<invalid>");
+        assert_eq!(os_str.to_string_or("<invalid>"), "<invalid>");
+        assert_eq!(os_str.to_os_string().to_string_or("<invalid>"), "<invalid>");
+    }
+
+    #[test]
+    fn cow_to_string() {
+        let path_str = "/path/to/whatever";
+
+        let path: Cow<'_, Path> = Cow::Borrowed(Path::new(path_str));
+        assert_eq!(path.to_lossy_string(), path_str);
+        assert_eq!(path.to_string().unwrap(), path_str);
+
+        let path: Cow<'_, Path> = Cow::Owned(PathBuf::from(path_str));
+        assert_eq!(path.to_lossy_string(), path_str);
+        assert_eq!(path.to_string().unwrap(), path_str);
+
+        let os_str: Cow<'_, OsStr> = Cow::Borrowed(OsStr::new(path_str));
+        assert_eq!(os_str.to_lossy_string(), path_str);
+        assert_eq!(os_str.to_string().unwrap(), path_str);
+
+        let os_str: Cow<'_, OsStr> = Cow::Owned(OsString::from(path_str));
+        assert_eq!(os_str.to_lossy_string(), path_str);
+        assert_eq!(os_str.to_string().unwrap(), path_str);
+    }
+
+    #[test]
+    fn path_name_ext() {
+        let path = Path::new("/path/to/archive.tar.gz");
+
+        assert_eq!(path.file_stem_string().unwrap(), "archive.tar");
+        assert_eq!(path.extension_string().unwrap(), "gz");
+
+        let path = PathBuf::from("/home/user/.bashrc");
+
+        assert_eq!(path.file_stem_string().unwrap(), ".bashrc");
+        assert_eq!(path.extension_string(), None);
+
+        let path = Path::new("/");
+
+        assert_eq!(path.file_stem_string(), None);
+        assert_eq!(path.extension_string(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn bytes_to_string() {
+        let bytes = b"/path/to/whatever".as_slice();
+
+        assert_eq!(bytes.to_lossy_string(), "/path/to/whatever");
+        assert_eq!(BytesToString::to_string(bytes).unwrap(), "/path/to/whatever");
+
+        let bytes = b"/path/to/\xffwhatever".as_slice();
+
+        assert_eq!(bytes.to_lossy_string(), "/path/to/\u{FFFD}whatever");
+        assert_eq!(BytesToString::to_string(bytes), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_to_dir_string() {
+        assert_eq!(Path::new("/a/b").to_dir_string(), "/a/b/");
+        assert_eq!(Path::new("/a/b/").to_dir_string(), "/a/b/");
+        assert_eq!(PathBuf::from("a").to_dir_string(), "a/");
+        assert_eq!(Path::new("/").to_dir_string(), "/");
+        assert_eq!(Path::new("").to_dir_string(), "/");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn path_to_dir_string() {
+        assert_eq!(Path::new(r"C:\a\b").to_dir_string(), r"C:\a\b\");
+        assert_eq!(Path::new(r"C:\a\b\").to_dir_string(), r"C:\a\b\");
+        assert_eq!(Path::new("").to_dir_string(), r"\");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn path_to_dir_string_forward_slash() {
+        assert_eq!(Path::new("C:/a/").to_dir_string(), "C:/a/");
+        assert_eq!(PathBuf::from("a/b/").to_dir_string(), "a/b/");
+        assert_eq!(Path::new("C:/a").to_dir_string(), r"C:/a\");
+    }
+
+    #[test]
+    fn paths_to_strings() {
+        let paths = vec![PathBuf::from("/usr/bin"), PathBuf::from("relative/path")];
+
+        assert_eq!(paths.to_lossy_strings(), ["/usr/bin", "relative/path"]);
+        assert_eq!(paths.to_strings().unwrap(), ["/usr/bin", "relative/path"]);
+
+        let paths: &[PathBuf] = &[];
+
+        assert!(paths.to_lossy_strings().is_empty());
+        assert!(paths.to_strings().unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn paths_to_strings_invalid_unicode() {
+        let paths = vec![
+            PathBuf::from("/usr/bin"),
+            PathBuf::from(OsStr::from_bytes(b"/\xffbin")),
+        ];
+
+        assert_eq!(paths.to_lossy_strings(), ["/usr/bin", "/\u{FFFD}bin"]);
+        assert_eq!(paths.to_strings(), None);
+    }
+
+    #[test]
+    fn first_existing_finds_real_file() {
+        use std::fs;
+
+        let dir = tempdir("first_existing");
+        let real = dir.join("real.toml");
+        fs::write(&real, "").unwrap();
+
+        let candidates = [dir.join("missing.toml"), real.clone(), dir.join("other.toml")];
+        assert_eq!(first_existing(candidates), Some(real));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn first_existing_none() {
+        assert_eq!(first_existing(Vec::<PathBuf>::new()), None);
+        assert_eq!(first_existing(["/path/to/nowhere", "/path/to/nothing"]), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn first_existing_non_unicode() {
+        use std::fs;
+
+        let dir = tempdir("first_existing_nu");
+        let real = dir.join(OsStr::from_bytes(b"\xffreal"));
+        fs::write(&real, "").unwrap();
+
+        assert_eq!(first_existing([dir.join("missing"), real.clone()]), Some(real));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn path_len_ext() {
+        let path = Path::new("/usr/local/bin");
+
+        assert_eq!(path.encoded_len(), 14);
+        assert_eq!(path.char_len_lossy(), 14);
+        assert_eq!(PathBuf::from("treats.toml").encoded_len(), 11);
+        assert_eq!(PathBuf::from("treats.toml").char_len_lossy(), 11);
+    }
+
+    #[test]
+    fn path_len_ext_empty() {
+        assert_eq!(Path::new("").encoded_len(), 0);
+        assert_eq!(PathBuf::new().char_len_lossy(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_len_ext_non_ascii() {
+        let path = Path::new("/tmp/🍬");
+
+        assert_eq!(path.encoded_len(), 9);
+        assert_eq!(path.char_len_lossy(), 6);
+
+        let path = Path::new(OsStr::from_bytes(b"/tmp/\xff\xfe"));
+
+        assert_eq!(path.encoded_len(), 7);
+        assert_eq!(path.char_len_lossy(), 7);
+    }
+
+    #[test]
+    fn sanitize_filename_plain() {
+        assert_eq!(sanitize_filename("notes.txt"), "notes.txt");
+        assert_eq!(sanitize_filename("naïve café.md"), "naïve café.md");
+    }
+
+    #[test]
+    fn sanitize_filename_invalid_characters() {
+        assert_eq!(sanitize_filename(r#"a/b\c:d*e?f"g<h>i|j"#), "a_b_c_d_e_f_g_h_i_j");
+        assert_eq!(sanitize_filename("tab\there\n"), "tab_here_");
+        assert_eq!(sanitize_filename("nul\0byte"), "nul_byte");
+    }
+
+    #[test]
+    fn sanitize_filename_traversal() {
+        assert_eq!(sanitize_filename(".."), "_");
+        assert_eq!(sanitize_filename("."), "_");
+        assert_eq!(sanitize_filename("../secret"), "_secret");
+        assert_eq!(sanitize_filename("../../etc/passwd"), "_.._etc_passwd");
+        assert_eq!(sanitize_filename(r"..\..\windows"), "_.._windows");
+        assert_eq!(sanitize_filename("/etc/passwd"), "_etc_passwd");
+    }
+
+    #[test]
+    fn sanitize_filename_trims_dots_and_spaces() {
+        assert_eq!(sanitize_filename("  .hidden. "), "hidden");
+        assert_eq!(sanitize_filename("a . b"), "a . b");
+    }
+
+    #[test]
+    fn sanitize_filename_fallback() {
+        assert_eq!(sanitize_filename(""), "_");
+        assert_eq!(sanitize_filename(" . . "), "_");
+    }
+
+    #[test]
+    fn sanitize_filename_reserved_names() {
+        assert_eq!(sanitize_filename("CON"), "CON_");
+        assert_eq!(sanitize_filename("con"), "con_");
+        assert_eq!(sanitize_filename("nul.txt"), "nul_.txt");
+        assert_eq!(sanitize_filename("LPT9.tar.gz"), "LPT9_.tar.gz");
+        assert_eq!(sanitize_filename("COM0"), "COM0");
+        assert_eq!(sanitize_filename("CONSOLE"), "CONSOLE");
+        assert_eq!(sanitize_filename("my con"), "my con");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn to_slash_string() {
+        assert_eq!(Path::new("/a/b/c").to_slash_string(), "/a/b/c");
+        assert_eq!(PathBuf::from("a/b").to_slash_string(), "a/b");
+        assert_eq!(Path::new(r"a\b").to_slash_string(), r"a\b");
+        assert_eq!(OsStr::new("a/b").to_slash_string(), "a/b");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn to_slash_string() {
+        assert_eq!(Path::new(r"C:\a\b").to_slash_string(), "C:/a/b");
+        assert_eq!(Path::new(r"C:\").to_slash_string(), "C:/");
+        assert_eq!(Path::new(r"a\b/c").to_slash_string(), "a/b/c");
+        assert_eq!(Path::new(r"\\server\share\a").to_slash_string(), "//server/share/a");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn from_slash_separators() {
+        assert_eq!(from_slash("/a/b/c"), Path::new("/a/b/c"));
+        assert_eq!(from_slash("a/b"), Path::new("a/b"));
+        assert_eq!(from_slash(""), Path::new(""));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn from_slash_separators() {
+        assert_eq!(from_slash("C:/a/b").as_os_str(), r"C:\a\b");
+        assert_eq!(from_slash("a/b").as_os_str(), r"a\b");
+        assert_eq!(from_slash("//server/share/a").as_os_str(), r"\\server\share\a");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn components_to_lossy_string_separators() {
+        assert_eq!(components_to_lossy_string(["usr", "local", "bin"]), "usr/local/bin");
+        assert_eq!(components_to_lossy_string(["usr", "local/bin"]), "usr/local/bin");
+        assert_eq!(components_to_lossy_string(["usr", "/bin"]), "usr//bin");
+        assert_eq!(components_to_lossy_string([Path::new("usr"), Path::new("")]), "usr/");
+        assert_eq!(components_to_lossy_string([OsStr::from_bytes(b"\xffbin")]), "\u{FFFD}bin");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn components_to_lossy_string_separators() {
+        assert_eq!(components_to_lossy_string(["usr", "local", "bin"]), r"usr\local\bin");
+        assert_eq!(components_to_lossy_string(["usr", "local/bin"]), r"usr\local/bin");
+        assert_eq!(components_to_lossy_string(["C:", "bin"]), r"C:\bin");
+    }
+
+    #[test]
+    fn components_to_lossy_string_edge_cases() {
+        assert_eq!(components_to_lossy_string(Vec::<&str>::new()), "");
+        assert_eq!(components_to_lossy_string(["treats"]), "treats");
+    }
+
+    #[test]
+    fn slash_round_trip() {
+        let path = Path::new("assets").join("icons").join("treat.png");
+
+        assert_eq!(from_slash(&path.to_slash_string()), path);
+    }
+
+    #[test]
+    fn to_shell_string() {
+        assert_eq!(Path::new("plain").to_shell_string(), "'plain'");
+        assert_eq!(Path::new("with spaces").to_shell_string(), "'with spaces'");
+        assert_eq!(Path::new("").to_shell_string(), "''");
+        assert_eq!(OsStr::new("$HOME `id` *; rm").to_shell_string(), "'$HOME `id` *; rm'");
+    }
+
+    #[test]
+    fn to_shell_string_quotes() {
+        assert_eq!(Path::new("tox's").to_shell_string(), r"'tox'\''s'");
+        assert_eq!(Path::new("'").to_shell_string(), r"''\'''");
+        assert_eq!(Path::new(r#"say "hi""#).to_shell_string(), r#"'say "hi"'"#);
+    }
 }
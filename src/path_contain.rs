@@ -0,0 +1,101 @@
+//! Convenience traits for checking whether [`Path`]s contain one another
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::path_relative::normalized_components;
+
+pub trait PathContainExt {
+    fn is_within<P: AsRef<Path>>(&self, base: P) -> bool;
+}
+
+impl PathContainExt for Path {
+    /// Checks whether a [`Path`] is `base` or somewhere under it.
+    ///
+    /// Both paths are normalized lexically, without touching the filesystem,
+    /// so `..` can't be used to escape `base`. Symlinks aren't followed, so
+    /// canonicalize both paths first if they might be involved.
+    ///
+    /// An absolute [`Path`] is never within a relative `base`, or vice versa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathContainExt;
+    ///
+    /// let root = Path::new("/srv/www");
+    ///
+    /// assert!(Path::new("/srv/www/index.html").is_within(root));
+    /// assert!(Path::new("/srv/www/img/../index.html").is_within(root));
+    /// assert!(!Path::new("/srv/www/../../etc/passwd").is_within(root));
+    /// ```
+    fn is_within<P: AsRef<Self>>(&self, base: P) -> bool {
+        let path = normalized_components(self);
+        let base = normalized_components(base.as_ref());
+
+        // Any `..` left past `base` climbs back out of it
+        path.strip_prefix(base.as_slice())
+            .is_some_and(|rest| !rest.contains(&Component::ParentDir))
+    }
+}
+
+impl PathContainExt for PathBuf {
+    /// Checks whether a [`PathBuf`] is `base` or somewhere under it.
+    ///
+    /// Both paths are normalized lexically, without touching the filesystem,
+    /// so `..` can't be used to escape `base`. Symlinks aren't followed, so
+    /// canonicalize both paths first if they might be involved.
+    #[inline]
+    fn is_within<P: AsRef<Path>>(&self, base: P) -> bool { self.as_path().is_within(base) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_within_nested() {
+        assert!(Path::new("/srv/www/index.html").is_within("/srv/www"));
+        assert!(Path::new("/srv/www/a/b/c").is_within("/srv"));
+        assert!(PathBuf::from("a/b").is_within("a"));
+        assert!(Path::new("/srv/www").is_within("/"));
+    }
+
+    #[test]
+    fn is_within_equal() {
+        assert!(Path::new("/srv/www").is_within("/srv/www"));
+        assert!(Path::new("/srv/www/").is_within("/srv/./www"));
+        assert!(Path::new("a").is_within("a"));
+        assert!(Path::new(".").is_within(""));
+    }
+
+    #[test]
+    fn is_within_normalizes() {
+        assert!(Path::new("/srv/www/img/../index.html").is_within("/srv/www"));
+        assert!(Path::new("/srv/www/index.html").is_within("/srv/tmp/../www"));
+        assert!(Path::new("a/./b").is_within("a"));
+    }
+
+    #[test]
+    fn is_within_traversal_escape() {
+        assert!(!Path::new("a/../b").is_within("a"));
+        assert!(!Path::new("/srv/www/../../etc/passwd").is_within("/srv/www"));
+        assert!(!Path::new("/srv/www/..").is_within("/srv/www"));
+        assert!(!Path::new("../a").is_within(""));
+        assert!(!Path::new("../../a").is_within(".."));
+        assert!(Path::new("../a").is_within(".."));
+    }
+
+    #[test]
+    fn is_within_sibling_prefix() {
+        assert!(!Path::new("/srv/www2").is_within("/srv/www"));
+        assert!(!Path::new("/srv").is_within("/srv/www"));
+    }
+
+    #[test]
+    fn is_within_mixed() {
+        assert!(!Path::new("/srv/www").is_within("srv"));
+        assert!(!Path::new("srv/www").is_within("/srv"));
+    }
+}
@@ -0,0 +1,110 @@
+//! Convenience traits for the depth of [`Path`]s
+
+use std::path::{Component, Path, PathBuf};
+
+pub trait PathDepthExt {
+    fn depth(&self) -> usize;
+
+    fn is_root(&self) -> bool;
+}
+
+impl PathDepthExt for Path {
+    /// Counts the components of a [`Path`] below its root.
+    ///
+    /// `.` isn't counted, but `..` is, since it isn't resolved. The root and
+    /// any Windows prefix aren't counted either, so `/` has a depth of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathDepthExt;
+    ///
+    /// let mut files = vec![Path::new("src/path/mod.rs"), Path::new("Cargo.toml")];
+    /// files.sort_by_key(|f| f.depth());
+    ///
+    /// assert_eq!(files, [Path::new("Cargo.toml"), Path::new("src/path/mod.rs")]);
+    /// ```
+    fn depth(&self) -> usize {
+        self.components()
+            .filter(|c| matches!(c, Component::Normal(_) | Component::ParentDir))
+            .count()
+    }
+
+    /// Checks whether a [`Path`] is just a root, like `/` or `C:\`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathDepthExt;
+    ///
+    /// assert!(Path::new("/").is_root());
+    /// assert!(!Path::new("/home").is_root());
+    /// ```
+    #[inline]
+    fn is_root(&self) -> bool { self.has_root() && self.depth() == 0 }
+}
+
+impl PathDepthExt for PathBuf {
+    /// Counts the components of a [`PathBuf`] below its root.
+    ///
+    /// `.` isn't counted, but `..` is, since it isn't resolved. The root and
+    /// any Windows prefix aren't counted either, so `/` has a depth of 0.
+    #[inline]
+    fn depth(&self) -> usize { self.as_path().depth() }
+
+    /// Checks whether a [`PathBuf`] is just a root, like `/` or `C:\`.
+    #[inline]
+    fn is_root(&self) -> bool { self.as_path().is_root() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn depth_absolute() {
+        assert_eq!(Path::new("/usr").depth(), 1);
+        assert_eq!(Path::new("/usr/local/bin").depth(), 3);
+        assert_eq!(PathBuf::from("/usr/local/bin/").depth(), 3);
+    }
+
+    #[test]
+    fn depth_relative() {
+        assert_eq!(Path::new("a").depth(), 1);
+        assert_eq!(Path::new("a/b/c").depth(), 3);
+        assert_eq!(Path::new("").depth(), 0);
+    }
+
+    #[test]
+    fn depth_root() {
+        assert_eq!(Path::new("/").depth(), 0);
+        assert!(Path::new("/").is_root());
+        assert!(PathBuf::from("/.").is_root());
+        assert!(!Path::new("/usr").is_root());
+        assert!(!Path::new("").is_root());
+        assert!(!Path::new(".").is_root());
+    }
+
+    #[test]
+    fn depth_dots() {
+        assert_eq!(Path::new("./a").depth(), 1);
+        assert_eq!(Path::new("a/./b").depth(), 2);
+        assert_eq!(Path::new(".").depth(), 0);
+        assert_eq!(Path::new("../a").depth(), 2);
+        assert_eq!(Path::new("a/../b").depth(), 3);
+        assert!(!Path::new("/..").is_root());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn depth_windows() {
+        assert_eq!(Path::new(r"C:\").depth(), 0);
+        assert_eq!(Path::new(r"C:\Users\tox").depth(), 2);
+        assert!(Path::new(r"C:\").is_root());
+        assert!(!Path::new("C:").is_root());
+    }
+}
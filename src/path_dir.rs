@@ -0,0 +1,157 @@
+//! Convenience traits for listing directories at [`Path`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub trait PathDirExt {
+    fn entry_names(&self) -> io::Result<Vec<String>>;
+
+    fn entry_paths(&self) -> io::Result<Vec<PathBuf>>;
+}
+
+impl PathDirExt for Path {
+    /// Lists the names of a directory's entries as [`String`]s.
+    ///
+    /// Names are converted lossily, and hidden entries are included. Like
+    /// [`fs::read_dir`], the order is platform-dependent.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::read_dir`], such as if the path isn't a
+    /// directory, or from reading an entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathDirExt;
+    ///
+    /// fn has_manifest(root: &Path) -> std::io::Result<bool> {
+    ///     Ok(root.entry_names()?.iter().any(|name| name == "Cargo.toml"))
+    /// }
+    /// ```
+    fn entry_names(&self) -> io::Result<Vec<String>> {
+        fs::read_dir(self)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect()
+    }
+
+    /// Lists the paths of a directory's entries.
+    ///
+    /// Each path is the directory joined with the entry's name, and hidden
+    /// entries are included. Like [`fs::read_dir`], the order is
+    /// platform-dependent.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::read_dir`], such as if the path isn't a
+    /// directory, or from reading an entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use treats::PathDirExt;
+    ///
+    /// fn subdirs(root: &Path) -> std::io::Result<Vec<PathBuf>> {
+    ///     Ok(root.entry_paths()?.into_iter().filter(|p| p.is_dir()).collect())
+    /// }
+    /// ```
+    fn entry_paths(&self) -> io::Result<Vec<PathBuf>> {
+        fs::read_dir(self)?.map(|entry| Ok(entry?.path())).collect()
+    }
+}
+
+impl PathDirExt for PathBuf {
+    /// Lists the names of a directory's entries as [`String`]s.
+    ///
+    /// Names are converted lossily, and hidden entries are included. Like
+    /// [`fs::read_dir`], the order is platform-dependent.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::read_dir`], such as if the path isn't a
+    /// directory, or from reading an entry.
+    #[inline]
+    fn entry_names(&self) -> io::Result<Vec<String>> { self.as_path().entry_names() }
+
+    /// Lists the paths of a directory's entries.
+    ///
+    /// Each path is the directory joined with the entry's name, and hidden
+    /// entries are included. Like [`fs::read_dir`], the order is
+    /// platform-dependent.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::read_dir`], such as if the path isn't a
+    /// directory, or from reading an entry.
+    #[inline]
+    fn entry_paths(&self) -> io::Result<Vec<PathBuf>> { self.as_path().entry_paths() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::tempdir;
+
+    fn populate(dir: &Path) {
+        fs::write(dir.join("a.txt"), "a").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+        fs::create_dir(dir.join("sub")).unwrap();
+        fs::write(dir.join("sub/nested.txt"), "nested").unwrap();
+    }
+
+    #[test]
+    fn entry_names() {
+        let dir = tempdir("entry_names");
+        populate(&dir);
+
+        let mut names = dir.entry_names().unwrap();
+        names.sort_unstable();
+        assert_eq!(names, [".hidden", "a.txt", "sub"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn entry_paths() {
+        let dir = tempdir("entry_paths");
+        populate(&dir);
+
+        let mut paths = dir.as_path().entry_paths().unwrap();
+        paths.sort_unstable();
+        assert_eq!(paths, [dir.join(".hidden"), dir.join("a.txt"), dir.join("sub")]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn entries_of_empty_dir() {
+        let dir = tempdir("entries_of_empty_dir");
+
+        assert!(dir.entry_names().unwrap().is_empty());
+        assert!(dir.entry_paths().unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn entries_of_non_directory() {
+        let dir = tempdir("entries_of_non_directory");
+        let file = dir.join("file.txt");
+        fs::write(&file, "").unwrap();
+
+        assert!(file.entry_names().is_err());
+        assert_eq!(
+            dir.join("absent").entry_paths().unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
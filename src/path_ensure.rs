@@ -0,0 +1,103 @@
+//! Convenience traits for ensuring [`Path`]s exist
+
+#![allow(clippy::missing_errors_doc)]
+
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::Permit;
+
+pub trait PathEnsureExt {
+    fn ensure_parent_dir(&self) -> io::Result<()>;
+}
+
+impl PathEnsureExt for Path {
+    /// Creates the parent directory of a [`Path`] and all of its ancestors,
+    /// permitting the case where it already exists.
+    ///
+    /// A [`Path`] without a parent, such as `/` or a bare filename, is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::create_dir_all`], unless the parent already
+    /// exists as a directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathEnsureExt;
+    ///
+    /// fn write_log(path: &Path, log: &str) -> std::io::Result<()> {
+    ///     path.ensure_parent_dir()?;
+    ///     std::fs::write(path, log)
+    /// }
+    /// ```
+    #[inline]
+    fn ensure_parent_dir(&self) -> io::Result<()> {
+        match self.parent() {
+            | Some(parent) if !parent.as_os_str().is_empty() => {
+                // If the parent exists but isn't a directory, that's still an error
+                fs::create_dir_all(parent)
+                    .permit(|e| e.kind() == ErrorKind::AlreadyExists && parent.is_dir())
+            },
+            | _ => Ok(()),
+        }
+    }
+}
+
+impl PathEnsureExt for PathBuf {
+    /// Creates the parent directory of a [`PathBuf`] and all of its ancestors,
+    /// permitting the case where it already exists.
+    ///
+    /// A [`PathBuf`] without a parent, such as `/` or a bare filename, is a
+    /// no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::create_dir_all`], unless the parent already
+    /// exists as a directory.
+    #[inline]
+    fn ensure_parent_dir(&self) -> io::Result<()> { self.as_path().ensure_parent_dir() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::tempdir;
+
+    #[test]
+    fn ensure_nested_parents() {
+        let dir = tempdir("ensure_nested_parents");
+        let file = dir.join("a/b/c/file.txt");
+
+        file.ensure_parent_dir().unwrap();
+        assert!(dir.join("a/b/c").is_dir());
+        assert!(!file.exists());
+
+        // Existing parents are fine too
+        file.ensure_parent_dir().unwrap();
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn ensure_no_parent() {
+        assert!(Path::new("/").ensure_parent_dir().is_ok());
+        assert!(Path::new("file.txt").ensure_parent_dir().is_ok());
+        assert!(Path::new("").ensure_parent_dir().is_ok());
+    }
+
+    #[test]
+    fn ensure_parent_is_file() {
+        let dir = tempdir("ensure_parent_is_file");
+        let file = dir.join("file.txt");
+        fs::write(&file, "").unwrap();
+
+        assert!(file.join("nested.txt").ensure_parent_dir().is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+//! Convenience traits for [`Path`] extensions
+
+use std::path::{Path, PathBuf};
+
+pub trait PathExtensionExt {
+    fn has_extension_ignore_case(&self, ext: &str) -> bool;
+
+    fn without_extension(&self) -> PathBuf;
+}
+
+impl PathExtensionExt for Path {
+    /// Checks whether a [`Path`] has the extension `ext`, ignoring case.
+    ///
+    /// `ext` may be given with or without a leading dot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathExtensionExt;
+    ///
+    /// assert!(Path::new("/tmp/photo.JPG").has_extension_ignore_case("jpg"));
+    /// assert!(Path::new("/tmp/photo.jpg").has_extension_ignore_case(".JPG"));
+    /// ```
+    #[inline]
+    fn has_extension_ignore_case(&self, ext: &str) -> bool {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+
+        self.extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.to_lowercase() == ext.to_lowercase())
+    }
+
+    /// Yields a [`PathBuf`] with the final extension of a [`Path`] removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathExtensionExt;
+    ///
+    /// assert_eq!(Path::new("/tmp/a.tar.gz").without_extension(), Path::new("/tmp/a.tar"));
+    /// ```
+    #[inline]
+    fn without_extension(&self) -> PathBuf { self.with_extension("") }
+}
+
+impl PathExtensionExt for PathBuf {
+    /// Checks whether a [`PathBuf`] has the extension `ext`, ignoring case.
+    ///
+    /// `ext` may be given with or without a leading dot.
+    #[inline]
+    fn has_extension_ignore_case(&self, ext: &str) -> bool {
+        self.as_path().has_extension_ignore_case(ext)
+    }
+
+    /// Yields a [`PathBuf`] with the final extension of a [`PathBuf`] removed.
+    #[inline]
+    fn without_extension(&self) -> PathBuf { self.as_path().without_extension() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_extension_ignore_case() {
+        let path = Path::new("/path/to/photo.JPG");
+
+        assert!(path.has_extension_ignore_case("jpg"));
+        assert!(path.has_extension_ignore_case("JPG"));
+        assert!(path.has_extension_ignore_case(".jpg"));
+        assert!(!path.has_extension_ignore_case("png"));
+        assert!(!path.has_extension_ignore_case("photo.jpg"));
+
+        let path = PathBuf::from("/path/to/Makefile");
+
+        assert!(!path.has_extension_ignore_case(""));
+        assert!(!path.has_extension_ignore_case("jpg"));
+    }
+
+    #[test]
+    fn without_extension() {
+        assert_eq!(Path::new("/path/to/a.tar.gz").without_extension(), Path::new("/path/to/a.tar"));
+        assert_eq!(PathBuf::from("photo.JPG").without_extension(), Path::new("photo"));
+
+        let path = Path::new("/path/to/Makefile");
+        assert_eq!(path.without_extension(), path);
+
+        let path = Path::new("/home/user/.bashrc");
+        assert_eq!(path.without_extension(), path);
+    }
+}
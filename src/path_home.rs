@@ -0,0 +1,96 @@
+//! Convenience traits for [`Path`]s relative to the home directory
+
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+pub trait PathHomeExt {
+    fn expand_home(&self) -> PathBuf;
+}
+
+/// Looks up the current user's home directory, ignoring empty values.
+fn home_dir() -> Option<OsString> {
+    let home = env::var_os("HOME");
+    #[cfg(windows)]
+    let home = home.filter(|h| !h.is_empty()).or_else(|| env::var_os("USERPROFILE"));
+    home.filter(|h| !h.is_empty())
+}
+
+fn expand_home_with(path: &Path, home: Option<OsString>) -> PathBuf {
+    match (path.strip_prefix("~"), home) {
+        | (Ok(rest), Some(home)) => {
+            // Avoid a trailing separator for a bare `~`
+            if rest.as_os_str().is_empty() {
+                PathBuf::from(home)
+            } else {
+                Path::new(&home).join(rest)
+            }
+        },
+        | _ => path.to_path_buf(),
+    }
+}
+
+impl PathHomeExt for Path {
+    /// Expands a leading `~` component of a [`Path`] to the home directory.
+    ///
+    /// The home directory is read from `HOME`, falling back to `USERPROFILE`
+    /// on Windows. If neither is set, or the [`Path`] doesn't start with `~`,
+    /// it's returned unchanged. Other users' homes, as in `~user`, aren't
+    /// expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathHomeExt;
+    ///
+    /// let config = Path::new("~/.config/treats.toml").expand_home();
+    /// if let Some(home) = std::env::var_os("HOME") {
+    ///     assert!(config.starts_with(home));
+    /// }
+    ///
+    /// assert_eq!(Path::new("~user/notes").expand_home(), Path::new("~user/notes"));
+    /// ```
+    #[inline]
+    fn expand_home(&self) -> PathBuf { expand_home_with(self, home_dir()) }
+}
+
+impl PathHomeExt for PathBuf {
+    /// Expands a leading `~` component of a [`PathBuf`] to the home
+    /// directory.
+    ///
+    /// If the home directory is unknown, or the [`PathBuf`] doesn't start with
+    /// `~`, it's returned unchanged.
+    #[inline]
+    fn expand_home(&self) -> PathBuf { self.as_path().expand_home() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_home_with_home() {
+        let home = Some(OsString::from("/home/treats"));
+
+        assert_eq!(expand_home_with(Path::new("~"), home.clone()), Path::new("/home/treats"));
+        assert_eq!(expand_home_with(Path::new("~/"), home.clone()), Path::new("/home/treats"));
+        assert_eq! {
+            expand_home_with(Path::new("~/.config/treats"), home),
+            Path::new("/home/treats/.config/treats")
+        };
+    }
+
+    #[test]
+    fn expand_home_passthrough() {
+        let home = Some(OsString::from("/home/treats"));
+
+        for path in ["~user/notes", "/etc/~/hosts", "notes/~", "~notes"] {
+            assert_eq!(expand_home_with(Path::new(path), home.clone()), Path::new(path));
+        }
+
+        assert_eq!(expand_home_with(Path::new("~/notes"), None), Path::new("~/notes"));
+        assert_eq!(PathBuf::from("notes.txt").expand_home(), Path::new("notes.txt"));
+    }
+}
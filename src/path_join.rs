@@ -0,0 +1,165 @@
+//! Convenience traits for joining [`Path`]s and building [`PathBuf`]s
+
+use std::path::{Path, PathBuf};
+
+pub trait PathJoinExt {
+    fn join_all<I, P>(&self, segments: I) -> PathBuf
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>;
+}
+
+impl PathJoinExt for Path {
+    /// Joins each of `segments` onto a [`Path`] in order.
+    ///
+    /// This folds over [`Path::join`], so an absolute segment replaces
+    /// everything before it, just like it would with
+    /// [`join`](Path::join). An empty `segments` yields the [`Path`] as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathJoinExt;
+    ///
+    /// let root = Path::new("/srv");
+    ///
+    /// assert_eq!(root.join_all(["www", "index.html"]), Path::new("/srv/www/index.html"));
+    /// assert_eq!(root.join_all(["www", "/etc", "hosts"]), Path::new("/etc/hosts"));
+    /// ```
+    #[inline]
+    fn join_all<I, P>(&self, segments: I) -> PathBuf
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Self>,
+    {
+        let mut path = self.to_path_buf();
+        // `push` has the same semantics as `join`, without reallocating each time
+        segments.into_iter().for_each(|segment| path.push(segment));
+        path
+    }
+}
+
+impl PathJoinExt for PathBuf {
+    /// Joins each of `segments` onto a [`PathBuf`] in order.
+    ///
+    /// An absolute segment replaces everything before it, like with
+    /// [`Path::join`].
+    #[inline]
+    fn join_all<I, P>(&self, segments: I) -> PathBuf
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.as_path().join_all(segments)
+    }
+}
+
+pub trait PathBufExt {
+    #[must_use]
+    fn pushed<P: AsRef<Path>>(self, p: P) -> Self;
+
+    #[must_use]
+    fn set_extension_owned(self, ext: &str) -> Self;
+}
+
+impl PathBufExt for PathBuf {
+    /// Pushes `p` onto a [`PathBuf`], returning it for chaining.
+    ///
+    /// This follows [`PathBuf::push`], so an absolute `p` replaces the whole
+    /// [`PathBuf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use treats::PathBufExt;
+    ///
+    /// let config = PathBuf::from("/etc").pushed("treats").pushed("config.toml");
+    /// assert_eq!(config, Path::new("/etc/treats/config.toml"));
+    /// ```
+    #[inline]
+    fn pushed<P: AsRef<Path>>(mut self, p: P) -> Self {
+        self.push(p);
+        self
+    }
+
+    /// Sets the extension of a [`PathBuf`], returning it for chaining.
+    ///
+    /// This follows [`PathBuf::set_extension`], so an empty `ext` removes the
+    /// extension, and a [`PathBuf`] without a file name is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use treats::PathBufExt;
+    ///
+    /// let backup = PathBuf::from("/srv").pushed("db.sqlite").set_extension_owned("bak");
+    /// assert_eq!(backup, Path::new("/srv/db.bak"));
+    /// ```
+    #[inline]
+    fn set_extension_owned(mut self, ext: &str) -> Self {
+        self.set_extension(ext);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_all_segments() {
+        let root = Path::new("root");
+
+        assert_eq!(root.join_all(["a", "b", "c"]), root.join("a").join("b").join("c"));
+        assert_eq! {
+            root.to_path_buf().join_all(vec![PathBuf::from("a/b"), PathBuf::from("c")]),
+            Path::new("root/a/b/c")
+        };
+    }
+
+    #[test]
+    fn join_all_empty() {
+        assert_eq!(Path::new("root").join_all([] as [&str; 0]), Path::new("root"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn join_all_absolute_resets() {
+        let root = Path::new("/root");
+
+        assert_eq!(root.join_all(["a", "/b", "c"]), Path::new("/b/c"));
+        assert_eq!(root.join_all(["a", "b", "/"]), Path::new("/"));
+    }
+
+    #[test]
+    fn pushed_fluently() {
+        assert_eq! {
+            PathBuf::from("root").pushed("a").pushed("b/c"),
+            Path::new("root").join("a").join("b/c")
+        };
+        assert_eq!(PathBuf::new().pushed("a"), Path::new("a"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pushed_absolute_replaces() {
+        assert_eq!(PathBuf::from("/root").pushed("a").pushed("/b"), Path::new("/b"));
+    }
+
+    #[test]
+    fn set_extension_owned() {
+        assert_eq!(PathBuf::from("a/b.txt").set_extension_owned("md"), Path::new("a/b.md"));
+        assert_eq!(PathBuf::from("a/b").set_extension_owned("md"), Path::new("a/b.md"));
+        assert_eq!(PathBuf::from("a/b.txt").set_extension_owned(""), Path::new("a/b"));
+        assert_eq! {
+            PathBuf::from("a").pushed("b").set_extension_owned("tar.gz"),
+            Path::new("a/b.tar.gz")
+        };
+    }
+}
@@ -0,0 +1,191 @@
+//! Convenience traits for the metadata of [`Path`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+pub trait PathMetaExt {
+    fn size(&self) -> io::Result<u64>;
+
+    fn is_empty_file(&self) -> io::Result<bool>;
+
+    fn modified_secs(&self) -> io::Result<u64>;
+}
+
+impl PathMetaExt for Path {
+    /// Yields the size of a file in bytes.
+    ///
+    /// Symlinks are followed, like with [`fs::metadata`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::metadata`], such as if the file doesn't
+    /// exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathMetaExt;
+    ///
+    /// fn too_big(path: &Path) -> std::io::Result<bool> { Ok(path.size()? > 1024 * 1024) }
+    /// ```
+    #[inline]
+    fn size(&self) -> io::Result<u64> { Ok(fs::metadata(self)?.len()) }
+
+    /// Checks whether a file is empty.
+    ///
+    /// Only files count, so a directory is never an empty file.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::metadata`], such as if the file doesn't
+    /// exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathMetaExt;
+    ///
+    /// fn has_content(path: &Path) -> std::io::Result<bool> { Ok(!path.is_empty_file()?) }
+    /// ```
+    #[inline]
+    fn is_empty_file(&self) -> io::Result<bool> {
+        let metadata = fs::metadata(self)?;
+        Ok(metadata.is_file() && metadata.len() == 0)
+    }
+
+    /// Yields when a file was last modified, in seconds since the unix epoch.
+    ///
+    /// Times before the epoch saturate to 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::metadata`], such as if the file doesn't
+    /// exist, or if the platform doesn't record modification times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathMetaExt;
+    ///
+    /// fn is_stale(output: &Path, input: &Path) -> std::io::Result<bool> {
+    ///     Ok(output.modified_secs()? < input.modified_secs()?)
+    /// }
+    /// ```
+    fn modified_secs(&self) -> io::Result<u64> {
+        let modified = fs::metadata(self)?.modified()?;
+        Ok(modified.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()))
+    }
+}
+
+impl PathMetaExt for PathBuf {
+    /// Yields the size of a file in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::metadata`], such as if the file doesn't
+    /// exist.
+    #[inline]
+    fn size(&self) -> io::Result<u64> { self.as_path().size() }
+
+    /// Checks whether a file is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::metadata`], such as if the file doesn't
+    /// exist.
+    #[inline]
+    fn is_empty_file(&self) -> io::Result<bool> { self.as_path().is_empty_file() }
+
+    /// Yields when a file was last modified, in seconds since the unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::metadata`], such as if the file doesn't
+    /// exist, or if the platform doesn't record modification times.
+    #[inline]
+    fn modified_secs(&self) -> io::Result<u64> { self.as_path().modified_secs() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::tempdir;
+
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn meta_empty_file() {
+        let dir = tempdir("meta_empty_file");
+        let file = dir.join("empty.txt");
+        fs::write(&file, "").unwrap();
+
+        assert_eq!(file.size().unwrap(), 0);
+        assert!(file.is_empty_file().unwrap());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn meta_non_empty_file() {
+        let dir = tempdir("meta_non_empty_file");
+        let file = dir.join("treats.txt");
+        fs::write(&file, "treats\n").unwrap();
+
+        assert_eq!(file.as_path().size().unwrap(), 7);
+        assert!(!file.is_empty_file().unwrap());
+        assert!(!dir.is_empty_file().unwrap());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn meta_modified_secs() {
+        let dir = tempdir("meta_modified_secs");
+        let file = dir.join("touched.txt");
+        fs::write(&file, "").unwrap();
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert!(file.modified_secs().unwrap().abs_diff(now) < 60);
+
+        let then = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        fs::File::options().write(true).open(&file).unwrap().set_modified(then).unwrap();
+        assert_eq!(file.modified_secs().unwrap(), 1_000_000_000);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn meta_modified_secs_before_epoch() {
+        let dir = tempdir("meta_modified_secs_before_epoch");
+        let file = dir.join("ancient.txt");
+        fs::write(&file, "").unwrap();
+
+        let before = UNIX_EPOCH - Duration::from_secs(10);
+        fs::File::options().write(true).open(&file).unwrap().set_modified(before).unwrap();
+        assert_eq!(file.modified_secs().unwrap(), 0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn meta_missing_file() {
+        let dir = tempdir("meta_missing_file");
+        let file = dir.join("missing.txt");
+
+        assert_eq!(file.size().unwrap_err().kind(), io::ErrorKind::NotFound);
+        assert_eq!(file.is_empty_file().unwrap_err().kind(), io::ErrorKind::NotFound);
+        assert_eq!(file.modified_secs().unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+//! Convenience traits for reading [`Path`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::PermitValue;
+
+pub trait PathReadExt {
+    fn read_to_string_or_empty(&self) -> io::Result<String>;
+}
+
+impl PathReadExt for Path {
+    /// Reads a file into a [`String`], permitting the case where it doesn't
+    /// exist.
+    ///
+    /// A missing file reads as an empty [`String`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::read_to_string`] other than
+    /// [`NotFound`](ErrorKind::NotFound), such as a permissions error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathReadExt;
+    ///
+    /// fn ignored(root: &Path) -> std::io::Result<Vec<String>> {
+    ///     let ignore = root.join(".ignore").read_to_string_or_empty()?;
+    ///     Ok(ignore.lines().map(ToString::to_string).collect())
+    /// }
+    /// ```
+    #[inline]
+    fn read_to_string_or_empty(&self) -> io::Result<String> {
+        fs::read_to_string(self).permit_or(|e| e.kind() == ErrorKind::NotFound, String::new())
+    }
+}
+
+impl PathReadExt for PathBuf {
+    /// Reads a file into a [`String`], permitting the case where it doesn't
+    /// exist.
+    ///
+    /// A missing file reads as an empty [`String`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`fs::read_to_string`] other than
+    /// [`NotFound`](ErrorKind::NotFound), such as a permissions error.
+    #[inline]
+    fn read_to_string_or_empty(&self) -> io::Result<String> {
+        self.as_path().read_to_string_or_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::tempdir;
+
+    #[test]
+    fn read_present_file() {
+        let dir = tempdir("read_present_file");
+        let file = dir.join("present.txt");
+        fs::write(&file, "treats\n").unwrap();
+
+        assert_eq!(file.read_to_string_or_empty().unwrap(), "treats\n");
+        assert_eq!(file.as_path().read_to_string_or_empty().unwrap(), "treats\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_absent_file() {
+        let dir = tempdir("read_absent_file");
+
+        assert_eq!(dir.join("absent.txt").read_to_string_or_empty().unwrap(), "");
+        assert_eq!(dir.join("absent/nested.txt").read_to_string_or_empty().unwrap(), "");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_unreadable_file() {
+        let dir = tempdir("read_unreadable_file");
+
+        // A directory can't be read as a file, regardless of who's running the
+        // tests, unlike permission bits which root ignores
+        assert!(dir.read_to_string_or_empty().is_err());
+
+        let file = dir.join("invalid.txt");
+        fs::write(&file, b"\xff\xfe").unwrap();
+        assert_eq!(file.read_to_string_or_empty().unwrap_err().kind(), ErrorKind::InvalidData);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir("read_permission_denied");
+        let file = dir.join("secret.txt");
+        fs::write(&file, "secret").unwrap();
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o000)).unwrap();
+
+        // Root can read the file anyway, so only check when that isn't the case
+        if fs::File::open(&file).is_err() {
+            assert_eq!(
+                file.read_to_string_or_empty().unwrap_err().kind(),
+                ErrorKind::PermissionDenied
+            );
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,220 @@
+//! Convenience traits for relative [`Path`]s
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::PathToString;
+
+pub trait PathRelativeExt {
+    fn strip_prefix_lossy<P: AsRef<Path>>(&self, base: P) -> String;
+
+    fn relative_to<P: AsRef<Path>>(&self, base: P) -> Option<PathBuf>;
+}
+
+/// Lexically normalizes a path's components, dropping `.` and resolving `..`
+/// against the preceding component.
+///
+/// Leading `..`s of relative paths are kept, since there's nothing to resolve
+/// them against.
+#[allow(clippy::redundant_pub_crate)] // `pub` would leak through `pub use`
+pub(crate) fn normalized_components(path: &Path) -> Vec<Component<'_>> {
+    let mut components = Vec::new();
+    for component in path.components() {
+        match component {
+            | Component::CurDir => {},
+            | Component::ParentDir => match components.last() {
+                | Some(Component::Normal(_)) => {
+                    components.pop();
+                },
+                // `..` at the root stays at the root
+                | Some(Component::RootDir | Component::Prefix(_)) => {},
+                | _ => components.push(component),
+            },
+            | component => components.push(component),
+        }
+    }
+    components
+}
+
+impl PathRelativeExt for Path {
+    /// Strips `base` from a [`Path`] and converts the rest into a lossy
+    /// [`String`].
+    ///
+    /// If the [`Path`] isn't under `base`, the whole [`Path`] is converted
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathRelativeExt;
+    ///
+    /// let root = Path::new("/home/user/project");
+    /// let lib = Path::new("/home/user/project/src/lib.rs");
+    ///
+    /// assert_eq!(lib.strip_prefix_lossy(root), "src/lib.rs");
+    /// assert_eq!(Path::new("/etc/hosts").strip_prefix_lossy(root), "/etc/hosts");
+    /// ```
+    #[inline]
+    fn strip_prefix_lossy<P: AsRef<Self>>(&self, base: P) -> String {
+        self.strip_prefix(base).unwrap_or(self).to_lossy_string()
+    }
+
+    /// Computes the relative path from `base` to a [`Path`].
+    ///
+    /// Both paths are normalized lexically, without touching the filesystem,
+    /// and a `..` is inserted for each component of `base` that isn't shared.
+    /// Identical paths yield an empty [`PathBuf`], and paths under `base`
+    /// yield just the suffix.
+    ///
+    /// Yields [`None`] if either path is relative, or if they're on different
+    /// Windows prefixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use treats::PathRelativeExt;
+    ///
+    /// let target = Path::new("/home/user/project/src/lib.rs");
+    ///
+    /// assert_eq!(target.relative_to("/home/user/project").unwrap(), Path::new("src/lib.rs"));
+    /// let docs = target.relative_to("/home/user/docs").unwrap();
+    /// assert_eq!(docs, Path::new("../project/src/lib.rs"));
+    /// assert_eq!(target.relative_to("relative/base"), None);
+    /// ```
+    fn relative_to<P: AsRef<Self>>(&self, base: P) -> Option<PathBuf> {
+        let base = base.as_ref();
+        if !self.is_absolute() || !base.is_absolute() {
+            return None;
+        }
+
+        let target = normalized_components(self);
+        let base = normalized_components(base);
+
+        // Different prefixes (or roots) can't be reached from one another
+        if target.first() != base.first() {
+            return None;
+        }
+
+        let shared = target.iter().zip(&base).take_while(|(t, b)| t == b).count();
+        let mut relative = PathBuf::new();
+        base[shared..].iter().for_each(|_| relative.push(".."));
+        target[shared..].iter().for_each(|component| relative.push(component));
+        Some(relative)
+    }
+}
+
+impl PathRelativeExt for PathBuf {
+    /// Strips `base` from a [`PathBuf`] and converts the rest into a lossy
+    /// [`String`].
+    ///
+    /// If the [`PathBuf`] isn't under `base`, the whole [`PathBuf`] is
+    /// converted instead.
+    #[inline]
+    fn strip_prefix_lossy<P: AsRef<Path>>(&self, base: P) -> String {
+        self.as_path().strip_prefix_lossy(base)
+    }
+
+    /// Computes the relative path from `base` to a [`PathBuf`].
+    ///
+    /// Yields [`None`] if either path is relative, or if they're on different
+    /// Windows prefixes.
+    #[inline]
+    fn relative_to<P: AsRef<Path>>(&self, base: P) -> Option<PathBuf> {
+        self.as_path().relative_to(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_prefix_lossy_under_base() {
+        let path = Path::new("/path/to/whatever");
+
+        assert_eq!(path.strip_prefix_lossy("/path"), "to/whatever");
+        assert_eq!(path.to_path_buf().strip_prefix_lossy("/path/to"), "whatever");
+    }
+
+    #[test]
+    fn strip_prefix_lossy_equal() {
+        let path = Path::new("/path/to/whatever");
+
+        assert_eq!(path.strip_prefix_lossy(path), "");
+    }
+
+    #[test]
+    fn strip_prefix_lossy_unrelated() {
+        let path = PathBuf::from("/path/to/whatever");
+
+        assert_eq!(path.strip_prefix_lossy("/somewhere/else"), "/path/to/whatever");
+        assert_eq!(path.strip_prefix_lossy("/path/to/what"), "/path/to/whatever");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_to_child() {
+        let path = Path::new("/path/to/whatever");
+
+        assert_eq!(path.relative_to("/path").unwrap(), Path::new("to/whatever"));
+        assert_eq!(path.relative_to("/").unwrap(), Path::new("path/to/whatever"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_to_identical() {
+        let path = PathBuf::from("/path/to/whatever");
+
+        assert_eq!(path.relative_to(&path).unwrap(), Path::new(""));
+        assert_eq!(path.relative_to("/path/./to/whatever/").unwrap(), Path::new(""));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_to_sibling() {
+        let path = Path::new("/home/user/project/src/lib.rs");
+
+        assert_eq! {
+            path.relative_to("/home/user/project/tests").unwrap(),
+            Path::new("../src/lib.rs")
+        };
+        assert_eq! {
+            path.relative_to("/home/other/docs").unwrap(),
+            Path::new("../../user/project/src/lib.rs")
+        };
+        assert_eq!(path.relative_to("/etc").unwrap(), Path::new("../home/user/project/src/lib.rs"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_to_ancestor() {
+        let path = Path::new("/path");
+
+        assert_eq!(path.relative_to("/path/to/whatever").unwrap(), Path::new("../.."));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_to_normalizes() {
+        let path = Path::new("/path/to/../from/./here");
+
+        assert_eq!(path.relative_to("/path/to").unwrap(), Path::new("../from/here"));
+        assert_eq!(Path::new("/../path").relative_to("/").unwrap(), Path::new("path"));
+    }
+
+    #[test]
+    fn relative_to_relative() {
+        assert_eq!(Path::new("path/to/whatever").relative_to("path"), None);
+        assert_eq!(Path::new("/path/to/whatever").relative_to("path"), None);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn relative_to_different_prefixes() {
+        assert_eq!(Path::new(r"C:\path\to").relative_to(r"D:\path"), None);
+        assert_eq!(Path::new(r"C:\path\to").relative_to(r"C:\path").unwrap(), Path::new("to"));
+    }
+}
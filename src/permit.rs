@@ -13,6 +13,39 @@ pub trait Permit<E> {
 
     #[must_use]
     fn permit_all(self) -> Self;
+
+    #[must_use]
+    fn permit_retry<F, G>(self, should_retry: F, op: G, max: usize) -> Self
+    where
+        F: Fn(&E) -> bool,
+        G: FnMut() -> Self;
+
+    #[must_use]
+    fn permit_or_else<F, G>(self, f: F, g: G) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+        G: FnOnce(E) -> Self;
+
+    #[must_use]
+    fn permit_or_cleanup<F, C>(self, permit: F, cleanup: C) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+        C: FnOnce(&E);
+
+    #[must_use]
+    fn permit_eq(self, err: E) -> Self
+    where
+        E: PartialEq;
+
+    #[must_use]
+    fn permit_in(self, errs: &[E]) -> Self
+    where
+        E: PartialEq;
+
+    fn permit_map_err<F, M, E2>(self, permit: F, map: M) -> Result<(), E2>
+    where
+        F: FnOnce(&E) -> bool,
+        M: FnOnce(E) -> E2;
 }
 
 impl<E> Permit<E> for Result<(), E> {
@@ -34,6 +67,40 @@ impl<E> Permit<E> for Result<(), E> {
     /// ```
     ///
     /// You can chain this
+    ///
+    /// Permitting before `?` only propagates the errors you didn't permit:
+    /// ```rust
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// use treats::Permit;
+    ///
+    /// fn setup() -> io::Result<()> {
+    ///     std::fs::create_dir("/tmp").permit(|e| e.kind() == ErrorKind::AlreadyExists)?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(setup().is_ok());
+    /// ```
+    ///
+    /// Since `&mut F` is [`FnOnce`] for any [`FnMut`] `F`, the same predicate
+    /// can be reused across a chain by passing it by mutable reference:
+    /// ```rust
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::Permit;
+    ///
+    /// let mut permitted = 0;
+    /// let mut already_exists = |e: &std::io::Error| {
+    ///     let is_permitted = e.kind() == ErrorKind::AlreadyExists;
+    ///     permitted += usize::from(is_permitted);
+    ///     is_permitted
+    /// };
+    ///
+    /// std::fs::create_dir("/tmp").permit(&mut already_exists).unwrap();
+    /// std::fs::create_dir("/tmp").permit(&mut already_exists).unwrap();
+    ///
+    /// assert_eq!(permitted, 2);
+    /// ```
     #[inline]
     fn permit<F>(self, f: F) -> Self
     where
@@ -57,6 +124,448 @@ impl<E> Permit<E> for Result<(), E> {
 
     #[inline]
     fn permit_all(self) -> Self { Ok(()) }
+
+    /// Retries `op` up to `max` times while the error is retriable
+    ///
+    /// Unlike [`Permit::permit`], this doesn't swallow the error but re-attempts
+    /// the operation. Returns the first [`Ok`], the first error that isn't
+    /// retriable, or the last error once `max` retries are used up. If `max` is
+    /// 0, the original result is returned as is.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // Retry an interrupted write a few times
+    /// use std::io::{ErrorKind, Write};
+    ///
+    /// use treats::Permit;
+    ///
+    /// let retriable = |e: &std::io::Error| e.kind() == ErrorKind::Interrupted;
+    ///
+    /// std::io::stdout()
+    ///     .flush()
+    ///     .permit_retry(retriable, || std::io::stdout().flush(), 3)
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    fn permit_retry<F, G>(self, should_retry: F, mut op: G, max: usize) -> Self
+    where
+        F: Fn(&E) -> bool,
+        G: FnMut() -> Self,
+    {
+        let mut result = self;
+        for _ in 0..max {
+            match result {
+                | Err(ref e) if should_retry(e) => result = op(), // retry the operation
+                | _ => break,                                     // otherwise, we're done
+            }
+        }
+        result
+    }
+
+    /// Permits a specific error, handing any other error to `g`
+    ///
+    /// `g` may recover from the error, map it, or return it as is.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // Create a directory, permit it already existing, and retry other errors once
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::Permit;
+    ///
+    /// let result = std::fs::create_dir("/tmp").permit_or_else(
+    ///     |e| e.kind() == ErrorKind::AlreadyExists,
+    ///     |_| std::fs::create_dir("/tmp"),
+    /// );
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    #[inline]
+    fn permit_or_else<F, G>(self, f: F, g: G) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+        G: FnOnce(E) -> Self,
+    {
+        match self {
+            | Ok(()) => Ok(()),             // if result is ok, return Ok(())
+            | Err(ref e) if f(e) => Ok(()), // permit the error and return Ok(())
+            | Err(e) => g(e),               // hand the error to g if not permitted
+        }
+    }
+
+    /// Permits a specific error, calling `cleanup` with any other error before
+    /// returning it
+    ///
+    /// This is the error path counterpart to [`Permit::permit`], for side
+    /// effects like logging or rolling back. `cleanup` isn't called for [`Ok`]
+    /// or a permitted error.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // Write a lockfile, removing the partial file if something went wrong
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::Permit;
+    ///
+    /// let lock = "/path/to/nonexistent/app.lock";
+    /// let result = std::fs::write(lock, std::process::id().to_string()).permit_or_cleanup(
+    ///     |e| e.kind() == ErrorKind::AlreadyExists,
+    ///     |e| {
+    ///         eprintln!("Failed to write {lock}: {e}");
+    ///         let _ = std::fs::remove_file(lock);
+    ///     },
+    /// );
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    #[inline]
+    fn permit_or_cleanup<F, C>(self, permit: F, cleanup: C) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+        C: FnOnce(&E),
+    {
+        match self {
+            | Ok(()) => Ok(()),                  // if result is ok, return Ok(())
+            | Err(ref e) if permit(e) => Ok(()), // permit the error and return Ok(())
+            | Err(e) => {
+                // clean up before returning the error if not permitted
+                cleanup(&e);
+                Err(e)
+            },
+        }
+    }
+
+    /// Permits an error equal to `err`
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use treats::Permit;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum DbError {
+    ///     NotFound,
+    ///     Corrupt,
+    /// }
+    ///
+    /// fn delete(id: u32) -> Result<(), DbError> {
+    ///     if id == 0 { Err(DbError::Corrupt) } else { Err(DbError::NotFound) }
+    /// }
+    ///
+    /// // Deleting something that doesn't exist is fine
+    /// assert_eq!(delete(1).permit_eq(DbError::NotFound), Ok(()));
+    /// assert_eq!(delete(0).permit_eq(DbError::NotFound), Err(DbError::Corrupt));
+    /// ```
+    #[inline]
+    fn permit_eq(self, err: E) -> Self
+    where
+        E: PartialEq,
+    {
+        self.permit(|e| *e == err)
+    }
+
+    /// Permits an error equal to any in `errs`
+    ///
+    /// An empty slice permits nothing.
+    #[inline]
+    fn permit_in(self, errs: &[E]) -> Self
+    where
+        E: PartialEq,
+    {
+        self.permit(|e| errs.contains(e))
+    }
+
+    /// Permits a specific error, mapping any other error with `map`
+    ///
+    /// `map` is only called for errors that aren't permitted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// use treats::Permit;
+    ///
+    /// #[derive(Debug)]
+    /// enum AppError {
+    ///     Io(io::Error),
+    /// }
+    ///
+    /// fn remove_lockfile() -> Result<(), AppError> {
+    ///     std::fs::remove_file("/tmp/treats.lock")
+    ///         .permit_map_err(|e| e.kind() == ErrorKind::NotFound, AppError::Io)
+    /// }
+    /// ```
+    #[inline]
+    fn permit_map_err<F, M, E2>(self, permit: F, map: M) -> Result<(), E2>
+    where
+        F: FnOnce(&E) -> bool,
+        M: FnOnce(E) -> E2,
+    {
+        self.permit(permit).map_err(map)
+    }
+}
+
+#[cfg(feature = "std")]
+pub trait PermitInto<E> {
+    #[must_use]
+    fn permit_into<F>(self, f: F, sink: &mut Vec<E>) -> Self
+    where
+        F: FnOnce(&E) -> bool;
+}
+
+#[cfg(feature = "std")]
+impl<E> PermitInto<E> for Result<(), E> {
+    /// Permits a specific error, collecting it into `sink`
+    ///
+    /// Unpermitted errors are returned as is, and `sink` is left untouched.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // Remove several files on a best-effort basis, reporting what was skipped
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitInto;
+    ///
+    /// let mut skipped = Vec::new();
+    /// for file in ["/tmp/treats-a.lock", "/tmp/treats-b.lock"] {
+    ///     std::fs::remove_file(file)
+    ///         .permit_into(|e| e.kind() == ErrorKind::NotFound, &mut skipped)
+    ///         .unwrap();
+    /// }
+    ///
+    /// eprintln!("skipped {} files", skipped.len());
+    /// ```
+    #[inline]
+    fn permit_into<F>(self, f: F, sink: &mut Vec<E>) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        match self {
+            | Err(e) if f(&e) => {
+                sink.push(e);
+                Ok(())
+            },
+            | result => result,
+        }
+    }
+}
+
+pub trait PermitValue<T, E> {
+    fn permit_or<F>(self, f: F, default: T) -> Result<T, E>
+    where
+        F: FnOnce(&E) -> bool;
+
+    fn permit_with<F, G>(self, f: F, g: G) -> Result<T, E>
+    where
+        F: FnOnce(&E) -> bool,
+        G: FnOnce() -> T;
+
+    fn permit_all_or(self, default: T) -> Result<T, E>;
+
+    fn permit_some<F>(self, f: F) -> Result<Option<T>, E>
+    where
+        F: FnOnce(&E) -> bool;
+
+    fn permit_default<F>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&E) -> bool,
+        T: Default;
+}
+
+impl<T, E> PermitValue<T, E> for Result<T, E> {
+    /// Permits a specific error for `Result<T, E>`, falling back to `default`
+    ///
+    /// Unlike [`Permit::permit`], this works for any `T`. The original value is
+    /// kept on [`Ok`], `default` is used if the error is permitted, and any
+    /// other error is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Read a config file, but treat a missing one as empty
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitValue;
+    ///
+    /// let config = std::fs::read_to_string("/path/to/nonexistent/config")
+    ///     .permit_or(|e| e.kind() == ErrorKind::NotFound, String::new());
+    ///
+    /// assert_eq!(config.unwrap(), "");
+    /// ```
+    #[inline]
+    fn permit_or<F>(self, f: F, default: T) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        match self {
+            | Ok(t) => Ok(t),
+            | Err(ref e) if f(e) => Ok(default),
+            | Err(e) => Err(e),
+        }
+    }
+
+    /// Permits a specific error for `Result<T, E>`, computing the fallback with
+    /// `g`
+    ///
+    /// This is the lazy version of [`PermitValue::permit_or`]. `g` is only
+    /// called if the error is permitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitValue;
+    ///
+    /// let motd = std::fs::read_to_string("/path/to/nonexistent/motd")
+    ///     .permit_with(|e| e.kind() == ErrorKind::NotFound, || "Welcome!".repeat(3));
+    ///
+    /// assert_eq!(motd.unwrap(), "Welcome!Welcome!Welcome!");
+    /// ```
+    #[inline]
+    fn permit_with<F, G>(self, f: F, g: G) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+        G: FnOnce() -> T,
+    {
+        match self {
+            | Ok(t) => Ok(t),
+            | Err(ref e) if f(e) => Ok(g()),
+            | Err(e) => Err(e),
+        }
+    }
+
+    /// Permits any error for `Result<T, E>`, falling back to `default`
+    ///
+    /// This never returns [`Err`], but still returns a [`Result`] for
+    /// consistency with the rest of [`PermitValue`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Best-effort read of the hostname
+    /// use treats::PermitValue;
+    ///
+    /// let hostname = std::fs::read_to_string("/etc/hostname")
+    ///     .permit_all_or("localhost".to_string())
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    fn permit_all_or(self, default: T) -> Self { Ok(self.unwrap_or(default)) }
+
+    /// Permits a specific error for `Result<T, E>`, yielding [`None`] in place
+    /// of a value
+    ///
+    /// This distinguishes a value that was actually produced (`Ok(Some(t))`)
+    /// from an error that was permitted (`Ok(None)`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Read an optional cache file
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitValue;
+    ///
+    /// match std::fs::read_to_string("/path/to/nonexistent/cache")
+    ///     .permit_some(|e| e.kind() == ErrorKind::NotFound)
+    /// {
+    ///     | Ok(Some(cache)) => println!("Loaded {} bytes of cache", cache.len()),
+    ///     | Ok(None) => println!("No cache yet"),
+    ///     | Err(e) => eprintln!("Failed to read cache: {e}"),
+    /// }
+    /// ```
+    ///
+    /// Before `?`, this propagates unpermitted errors and leaves an [`Option`]
+    /// for the caller to handle:
+    /// ```
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// use treats::PermitValue;
+    ///
+    /// fn cache_len() -> io::Result<usize> {
+    ///     let cache = std::fs::read_to_string("/path/to/nonexistent/cache")
+    ///         .permit_some(|e| e.kind() == ErrorKind::NotFound)?;
+    ///     Ok(cache.map_or(0, |c| c.len()))
+    /// }
+    ///
+    /// assert_eq!(cache_len().unwrap(), 0);
+    /// ```
+    #[inline]
+    fn permit_some<F>(self, f: F) -> Result<Option<T>, E>
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        match self {
+            | Ok(t) => Ok(Some(t)),
+            | Err(ref e) if f(e) => Ok(None),
+            | Err(e) => Err(e),
+        }
+    }
+
+    /// Permits a specific error for `Result<T, E>`, falling back to
+    /// [`T::default()`](Default::default)
+    ///
+    /// This is equivalent to [`PermitValue::permit_with`] with
+    /// [`Default::default`], and only requires `T: Default` for this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::PermitValue;
+    ///
+    /// // Treat an empty count as zero, but still reject garbage
+    /// fn count(s: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     s.parse().permit_default(|_| s.is_empty())
+    /// }
+    ///
+    /// assert_eq!(count("42"), Ok(42));
+    /// assert_eq!(count(""), Ok(0));
+    /// assert!(count("many").is_err());
+    /// ```
+    #[inline]
+    fn permit_default<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+        T: Default,
+    {
+        self.permit_with(f, T::default)
+    }
+}
+
+pub trait PermitRef<E> {
+    fn is_permitted<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&E) -> bool;
+}
+
+impl<E> PermitRef<E> for &Result<(), E> {
+    /// Checks whether a borrowed `Result<(), E>` would pass [`Permit::permit`]
+    ///
+    /// Returns `true` for [`Ok`] without calling `f`, or for an [`Err`] that
+    /// `f` permits. The result isn't consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitRef;
+    ///
+    /// let cached = std::fs::remove_file("/path/to/nonexistent/file");
+    ///
+    /// assert!((&cached).is_permitted(|e| e.kind() == ErrorKind::NotFound));
+    /// assert!(cached.is_err());
+    /// ```
+    #[inline]
+    fn is_permitted<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        match self {
+            | Ok(()) => true,
+            | Err(e) => f(e),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -66,7 +575,7 @@ mod tests {
 
     use std::fs;
     use std::io::{self, ErrorKind, Write};
-    use std::string::ToString;
+    use std::string::{String, ToString};
 
     fn ls(path: &str) -> anyhow::Result<()> {
         fs::read_dir(path)?;
@@ -147,4 +656,441 @@ mod tests {
                 .is_ok()
         }
     }
+
+    #[test]
+    fn permit_or_and_fall_back() {
+        assert_eq! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_or(|e| e.kind() == ErrorKind::NotFound, "fallback".to_string())
+                .unwrap(),
+            "fallback"
+        }
+    }
+
+    #[test]
+    fn permit_or_and_fail() {
+        assert! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_or(|e| e.kind() == ErrorKind::PermissionDenied, String::new())
+                .is_err()
+        }
+    }
+
+    #[test]
+    fn permit_or_keeps_value() {
+        let x: Result<i32, &str> = Ok(7);
+
+        assert_eq! {
+            x.permit_or(|_| true, 0),
+            Ok(7)
+        }
+    }
+
+    #[test]
+    fn permit_with_calls_once() {
+        let mut calls = 0;
+
+        assert_eq! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_with(|e| e.kind() == ErrorKind::NotFound, || {
+                    calls += 1;
+                    "fallback".to_string()
+                })
+                .unwrap(),
+            "fallback"
+        }
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn permit_with_never_calls() {
+        let mut calls = 0;
+
+        assert! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_with(|e| e.kind() == ErrorKind::PermissionDenied, || {
+                    calls += 1;
+                    String::new()
+                })
+                .is_err()
+        }
+
+        let x: Result<i32, &str> = Ok(7);
+
+        assert_eq! {
+            x.permit_with(|_| true, || {
+                calls += 1;
+                0
+            }),
+            Ok(7)
+        }
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn permit_all_or_and_fall_back() {
+        assert_eq! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_all_or("fallback".to_string())
+                .unwrap(),
+            "fallback"
+        }
+    }
+
+    #[test]
+    fn permit_all_or_keeps_value() {
+        let x: Result<i32, &str> = Ok(7);
+
+        assert_eq! {
+            x.permit_all_or(0),
+            Ok(7)
+        }
+    }
+
+    #[test]
+    fn chain_with_shared_fn_mut() {
+        let mut count = 0;
+        let mut pred = |e: &io::Error| {
+            count += 1;
+            e.kind() == io::ErrorKind::NotFound
+        };
+
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit(&mut pred)
+                .permit(&mut pred)
+                .is_ok()
+        }
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit(|e| e.kind() == io::ErrorKind::PermissionDenied)
+                .permit(&mut pred)
+                .is_ok()
+        }
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn permit_some_keeps_value() {
+        let x: Result<i32, &str> = Ok(7);
+
+        assert_eq! {
+            x.permit_some(|_| true),
+            Ok(Some(7))
+        }
+    }
+
+    #[test]
+    fn permit_some_and_succeed() {
+        assert_eq! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_some(|e| e.kind() == ErrorKind::NotFound)
+                .unwrap(),
+            None
+        }
+    }
+
+    #[test]
+    fn permit_some_and_fail() {
+        assert! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_some(|e| e.kind() == ErrorKind::PermissionDenied)
+                .is_err()
+        }
+    }
+
+    fn read_optional(path: &str, permitted: ErrorKind) -> io::Result<Option<usize>> {
+        let contents = fs::read_to_string(path).permit_some(|e| e.kind() == permitted)?;
+        Ok(contents.map(|c| c.len()))
+    }
+
+    #[test]
+    fn permit_some_through_question_mark() {
+        assert_eq! {
+            read_optional("/path/to/nonexistent/file", ErrorKind::NotFound).unwrap(),
+            None
+        }
+        assert_eq! {
+            read_optional("/path/to/nonexistent/file", ErrorKind::PermissionDenied)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NotFound
+        }
+    }
+
+    fn remove_optional(path: &str) -> io::Result<&'static str> {
+        fs::remove_file(path).permit(|e| e.kind() == ErrorKind::NotFound)?;
+        Ok("removed")
+    }
+
+    #[test]
+    fn permit_through_question_mark() {
+        assert_eq!(remove_optional("/path/to/nonexistent/file").unwrap(), "removed");
+        assert!(remove_optional("/path/to/nonexistent/file\0").is_err());
+    }
+
+    #[test]
+    fn permit_default_string() {
+        assert_eq! {
+            fs::read_to_string("/path/to/nonexistent/file")
+                .permit_default(|e| e.kind() == ErrorKind::NotFound)
+                .unwrap(),
+            ""
+        }
+
+        let x: Result<String, TestError> = Ok("kept".to_string());
+        assert_eq!(x.permit_default(|_| true).unwrap(), "kept");
+    }
+
+    #[test]
+    fn permit_default_integer() {
+        assert_eq!("".parse::<u32>().permit_default(|_| true), Ok(0));
+        assert_eq!("7".parse::<u32>().permit_default(|_| true), Ok(7));
+        assert!("x".parse::<u32>().permit_default(|_| false).is_err());
+    }
+
+    #[test]
+    fn permit_retry_and_succeed() {
+        let mut failures = 2;
+        let mut op = || {
+            if failures > 0 {
+                failures -= 1;
+                return Err(io::Error::from(ErrorKind::Interrupted));
+            }
+            Ok(())
+        };
+
+        let result = op().permit_retry(|e| e.kind() == ErrorKind::Interrupted, op, 3);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn permit_retry_and_run_out() {
+        let mut attempts = 0;
+        let result = Err(io::Error::from(ErrorKind::Interrupted)).permit_retry(
+            |e| e.kind() == ErrorKind::Interrupted,
+            || {
+                attempts += 1;
+                Err(io::Error::from(ErrorKind::Interrupted))
+            },
+            3,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn permit_retry_not_retriable() {
+        let mut attempts = 0;
+        let result = Err(io::Error::from(ErrorKind::NotFound)).permit_retry(
+            |e| e.kind() == ErrorKind::Interrupted,
+            || {
+                attempts += 1;
+                Ok(())
+            },
+            3,
+        );
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotFound);
+        assert_eq!(attempts, 0);
+    }
+
+    #[test]
+    fn permit_retry_zero_times() {
+        let mut attempts = 0;
+        let result = Err(io::Error::from(ErrorKind::Interrupted)).permit_retry(
+            |e| e.kind() == ErrorKind::Interrupted,
+            || {
+                attempts += 1;
+                Ok(())
+            },
+            0,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 0);
+    }
+
+    #[test]
+    fn permit_or_else_permitted() {
+        let mut handled = false;
+        let x: Result<(), &str> = Err("permitted");
+
+        assert_eq! {
+            x.permit_or_else(|e| *e == "permitted", |e| {
+                handled = true;
+                Err(e)
+            }),
+            Ok(())
+        }
+        assert!(!handled);
+    }
+
+    #[test]
+    fn permit_or_else_recover() {
+        let x: Result<(), &str> = Err("recoverable");
+
+        assert_eq! {
+            x.permit_or_else(|e| *e == "permitted", |_| Ok(())),
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn permit_or_else_re_error() {
+        let x: Result<(), &str> = Err("fatal");
+
+        assert_eq! {
+            x.permit_or_else(|e| *e == "permitted", |_| Err("still fatal")),
+            Err("still fatal")
+        }
+    }
+
+    #[test]
+    fn permit_or_cleanup_permitted() {
+        let mut cleaned = false;
+        let x: Result<(), &str> = Err("permitted");
+
+        assert_eq!(x.permit_or_cleanup(|e| *e == "permitted", |_| cleaned = true), Ok(()));
+        assert!(!cleaned);
+    }
+
+    #[test]
+    fn permit_or_cleanup_unpermitted() {
+        let mut cleaned = None;
+        let x: Result<(), &str> = Err("fatal");
+
+        assert_eq! {
+            x.permit_or_cleanup(|e| *e == "permitted", |e| cleaned = Some(*e)),
+            Err("fatal")
+        }
+        assert_eq!(cleaned, Some("fatal"));
+    }
+
+    #[test]
+    fn permit_or_cleanup_ok() {
+        let mut cleaned = false;
+        let x: Result<(), &str> = Ok(());
+
+        assert_eq!(x.permit_or_cleanup(|_| unreachable!(), |_| cleaned = true), Ok(()));
+        assert!(!cleaned);
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum TestError {
+        NotFound,
+        Busy,
+        Corrupt,
+    }
+
+    #[test]
+    fn permit_eq() {
+        assert_eq!(Err(TestError::NotFound).permit_eq(TestError::NotFound), Ok(()));
+        assert_eq! {
+            Err(TestError::Corrupt).permit_eq(TestError::NotFound),
+            Err(TestError::Corrupt)
+        }
+        assert_eq!(Ok(()).permit_eq(TestError::NotFound), Ok(()));
+    }
+
+    #[test]
+    fn permit_in() {
+        let permitted = [TestError::NotFound, TestError::Busy];
+
+        assert_eq!(Err(TestError::Busy).permit_in(&permitted), Ok(()));
+        assert_eq!(Err(TestError::Corrupt).permit_in(&permitted), Err(TestError::Corrupt));
+        assert_eq!(Err(TestError::NotFound).permit_in(&[]), Err(TestError::NotFound));
+    }
+
+    #[test]
+    fn is_permitted_ok() {
+        let x: Result<(), TestError> = Ok(());
+
+        assert!((&x).is_permitted(|_| false));
+    }
+
+    #[test]
+    fn is_permitted_permitted() {
+        let x = Err(TestError::Busy);
+
+        assert!((&x).is_permitted(|e| *e == TestError::Busy));
+        assert_eq!(x, Err(TestError::Busy));
+    }
+
+    #[test]
+    fn is_permitted_unpermitted() {
+        let x = Err(TestError::Corrupt);
+
+        assert!(!(&x).is_permitted(|e| *e == TestError::Busy));
+    }
+
+    #[test]
+    fn permit_map_err_permitted() {
+        let mut mapped = false;
+        let result = Err(TestError::NotFound).permit_map_err(
+            |e| *e == TestError::NotFound,
+            |_| {
+                mapped = true;
+                "mapped"
+            },
+        );
+
+        assert_eq!(result, Ok(()));
+        assert!(!mapped);
+    }
+
+    #[test]
+    fn permit_map_err_unpermitted() {
+        let result: Result<(), &str> = Err(TestError::Corrupt)
+            .permit_map_err(|e| *e == TestError::NotFound, |_| "corrupt");
+
+        assert_eq!(result, Err("corrupt"));
+    }
+
+    #[test]
+    fn permit_map_err_ok() {
+        let mut mapped = false;
+        let result = Ok::<(), TestError>(()).permit_map_err(
+            |_| false,
+            |_| {
+                mapped = true;
+                "mapped"
+            },
+        );
+
+        assert_eq!(result, Ok(()));
+        assert!(!mapped);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn permit_into_permitted() {
+        let mut sink = Vec::new();
+
+        assert_eq!(Err(TestError::NotFound).permit_into(|_| true, &mut sink), Ok(()));
+        assert_eq!(sink, [TestError::NotFound]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn permit_into_unpermitted() {
+        let mut sink = Vec::new();
+
+        assert_eq! {
+            Err(TestError::Corrupt).permit_into(|e| *e == TestError::NotFound, &mut sink),
+            Err(TestError::Corrupt)
+        }
+        assert!(sink.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn permit_into_ok() {
+        let mut sink: Vec<TestError> = Vec::new();
+
+        assert_eq!(Ok(()).permit_into(|_| unreachable!(), &mut sink), Ok(()));
+        assert!(sink.is_empty());
+    }
 }
@@ -0,0 +1,110 @@
+//! Permit [`anyhow::Error`]s by their concrete type
+
+use std::error::Error;
+
+use crate::Permit;
+
+pub trait PermitDowncast {
+    #[must_use]
+    fn permit_downcast<T>(self, f: impl FnOnce(&T) -> bool) -> Self
+    where
+        T: Error + Send + Sync + 'static;
+}
+
+impl PermitDowncast for anyhow::Result<()> {
+    /// Permits an [`anyhow::Error`] if it downcasts to `T` and `f` holds
+    ///
+    /// If the error isn't a `T`, it's returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitDowncast;
+    ///
+    /// fn ls(path: &str) -> anyhow::Result<()> {
+    ///     std::fs::read_dir(path)?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert! {
+    ///     ls("/path/to/nonexistent/directory")
+    ///         .permit_downcast::<std::io::Error>(|e| e.kind() == ErrorKind::NotFound)
+    ///         .is_ok()
+    /// }
+    /// ```
+    #[inline]
+    fn permit_downcast<T>(self, f: impl FnOnce(&T) -> bool) -> Self
+    where
+        T: Error + Send + Sync + 'static,
+    {
+        self.permit(|e| e.downcast_ref::<T>().is_some_and(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt;
+    use std::io::{self, ErrorKind};
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum ConfigError {
+        Missing,
+        Invalid,
+    }
+
+    impl fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                | Self::Missing => write!(f, "config is missing"),
+                | Self::Invalid => write!(f, "config is invalid"),
+            }
+        }
+    }
+
+    impl Error for ConfigError {}
+
+    fn load(err: ConfigError) -> anyhow::Result<()> { Err(err.into()) }
+
+    #[test]
+    fn permit_custom_error() {
+        assert! {
+            load(ConfigError::Missing)
+                .permit_downcast::<ConfigError>(|e| *e == ConfigError::Missing)
+                .is_ok()
+        }
+    }
+
+    #[test]
+    fn permit_custom_error_and_fail() {
+        assert! {
+            load(ConfigError::Invalid)
+                .permit_downcast::<ConfigError>(|e| *e == ConfigError::Missing)
+                .is_err()
+        }
+    }
+
+    #[test]
+    fn permit_mismatched_type() {
+        let result = load(ConfigError::Missing).permit_downcast::<io::Error>(|_| true);
+
+        assert_eq! {
+            result.unwrap_err().downcast_ref::<ConfigError>(),
+            Some(&ConfigError::Missing)
+        }
+    }
+
+    #[test]
+    fn permit_io_error() {
+        let result: anyhow::Result<()> = Err(io::Error::from(ErrorKind::NotFound).into());
+
+        assert! {
+            result
+                .permit_downcast::<io::Error>(|e| e.kind() == ErrorKind::NotFound)
+                .is_ok()
+        }
+    }
+}
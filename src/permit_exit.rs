@@ -0,0 +1,113 @@
+//! Permit exit codes for [`io::Result<ExitStatus>`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+use std::io;
+use std::process::ExitStatus;
+
+pub trait PermitExit {
+    fn permit_code(self, code: i32) -> io::Result<ExitStatus>;
+
+    fn permit_success(self) -> io::Result<ExitStatus>;
+}
+
+impl PermitExit for io::Result<ExitStatus> {
+    /// Treats a process that succeeded or exited with `code` as [`Ok`]
+    ///
+    /// Any other exit status becomes an [`io::Error`], as does a process that
+    /// failed to run at all. Since a permitted status stays [`Ok`] and anything
+    /// else becomes an error, this doesn't chain like `Permit::permit` from the
+    /// `permit` feature.
+    ///
+    /// On unix, a process terminated by a signal has no exit code, so it's
+    /// never permitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // grep exits with 1 if nothing matched, which is fine
+    /// use std::process::Command;
+    ///
+    /// use treats::PermitExit;
+    ///
+    /// let status = Command::new("grep")
+    ///     .args(["-q", "some pattern", "/dev/null"])
+    ///     .status()
+    ///     .permit_code(1);
+    /// ```
+    #[inline]
+    fn permit_code(self, code: i32) -> Self {
+        match self {
+            | Ok(status) if status.success() || status.code() == Some(code) => Ok(status),
+            | Ok(status) => Err(io::Error::other(format!("process failed with {status}"))),
+            | Err(e) => Err(e),
+        }
+    }
+
+    /// Treats only a process that succeeded as [`Ok`]
+    ///
+    /// Any other exit status becomes an [`io::Error`], as does a process that
+    /// failed to run at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::process::Command;
+    ///
+    /// use treats::PermitExit;
+    ///
+    /// fn git_pull() -> std::io::Result<()> {
+    ///     Command::new("git").arg("pull").status().permit_success()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    fn permit_success(self) -> Self {
+        match self {
+            | Ok(status) if status.success() => Ok(status),
+            | Ok(status) => Err(io::Error::other(format!("process failed with {status}"))),
+            | Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::Command;
+
+    #[test]
+    fn permit_success() {
+        assert!(Command::new("true").status().permit_success().is_ok());
+        assert!(Command::new("false").status().permit_success().is_err());
+    }
+
+    #[test]
+    fn permit_code() {
+        assert!(Command::new("true").status().permit_code(1).is_ok());
+        assert!(Command::new("false").status().permit_code(1).is_ok());
+        assert!(Command::new("false").status().permit_code(2).is_err());
+    }
+
+    #[test]
+    fn permit_code_failed_spawn() {
+        assert! {
+            Command::new("/path/to/nonexistent/command")
+                .status()
+                .permit_code(1)
+                .is_err()
+        }
+    }
+
+    #[test]
+    fn permit_code_signal() {
+        // Terminated by SIGKILL
+        let status = ExitStatus::from_raw(9);
+
+        assert_eq!(status.code(), None);
+        assert!(Ok(status).permit_code(9).is_err());
+        assert!(Ok(status).permit_code(0).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+//! Permit [`io::Error`]s by [`ErrorKind`]
+
+use std::io::{self, ErrorKind};
+
+use crate::Permit;
+
+pub trait PermitIo {
+    #[must_use]
+    fn permit_kind(self, kind: ErrorKind) -> Self;
+
+    #[must_use]
+    fn permit_kinds(self, kinds: &[ErrorKind]) -> Self;
+}
+
+impl PermitIo for Result<(), io::Error> {
+    /// Permits an [`io::Error`] of a specific [`ErrorKind`]
+    ///
+    /// This is shorthand for `.permit(|e| e.kind() == kind)`. Like
+    /// [`Permit`], it only works for `io::Result<()>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Create a directory, but permit the case where it already exists
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitIo;
+    ///
+    /// if let Err(e) = std::fs::create_dir("/tmp").permit_kind(ErrorKind::AlreadyExists) {
+    ///     eprintln!("Failed to create /tmp: {e}")
+    /// }
+    /// ```
+    ///
+    /// You can chain this
+    #[inline]
+    fn permit_kind(self, kind: ErrorKind) -> Self { self.permit(|e| e.kind() == kind) }
+
+    /// Permits an [`io::Error`] of any of the given [`ErrorKind`]s
+    ///
+    /// An empty slice permits nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Remove a file, but permit the case where it's already gone or was never ours
+    /// use std::io::ErrorKind;
+    ///
+    /// use treats::PermitIo;
+    ///
+    /// if let Err(e) = std::fs::remove_file("/tmp/some.lock")
+    ///     .permit_kinds(&[ErrorKind::NotFound, ErrorKind::PermissionDenied])
+    /// {
+    ///     eprintln!("Failed to remove /tmp/some.lock: {e}")
+    /// }
+    /// ```
+    #[inline]
+    fn permit_kinds(self, kinds: &[ErrorKind]) -> Self {
+        self.permit(|e| kinds.contains(&e.kind()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    #[test]
+    fn permit_already_exists() {
+        assert! {
+            fs::create_dir("/tmp")
+                .permit_kind(ErrorKind::AlreadyExists)
+                .is_ok()
+        }
+    }
+
+    #[test]
+    fn permit_not_found() {
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit_kind(ErrorKind::NotFound)
+                .is_ok()
+        }
+    }
+
+    #[test]
+    fn permit_wrong_kind() {
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit_kind(ErrorKind::AlreadyExists)
+                .is_err()
+        }
+    }
+
+    #[test]
+    fn chain_and_succeed() {
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit_kind(ErrorKind::AlreadyExists)
+                .permit_kind(ErrorKind::NotFound)
+                .is_ok()
+        }
+    }
+
+    #[test]
+    fn permit_second_kind() {
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit_kinds(&[ErrorKind::AlreadyExists, ErrorKind::NotFound])
+                .is_ok()
+        }
+    }
+
+    #[test]
+    fn permit_duplicate_kinds() {
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit_kinds(&[ErrorKind::NotFound, ErrorKind::NotFound])
+                .is_ok()
+        }
+    }
+
+    #[test]
+    fn permit_unlisted_kind() {
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit_kinds(&[ErrorKind::AlreadyExists, ErrorKind::PermissionDenied])
+                .is_err()
+        }
+    }
+
+    #[test]
+    fn permit_no_kinds() {
+        assert! {
+            fs::remove_file("/path/to/nonexistent/file")
+                .permit_kinds(&[])
+                .is_err()
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Permit errors for [`Result`]s, logging them with [`log`]
+
+use core::fmt::Display;
+
+use crate::Permit;
+
+pub trait PermitLogged<E> {
+    #[must_use]
+    fn permit_logged<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> bool;
+}
+
+impl<E: Display> PermitLogged<E> for Result<(), E> {
+    /// Permits a specific error for `Result<(), E>`, logging it with
+    /// [`log::warn!`]
+    ///
+    /// Errors that aren't permitted are returned as is and aren't logged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // Attempt to create a directory, but permit (and log) the case where it already exists
+    /// use treats::PermitLogged;
+    ///
+    /// if let Err(e) = std::fs::create_dir("/tmp/dir")
+    ///     .permit_logged(|e| e.kind() == std::io::ErrorKind::AlreadyExists)
+    /// {
+    ///     eprintln!("Failed to create /tmp/dir: {e}")
+    /// }
+    /// ```
+    #[inline]
+    fn permit_logged<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        self.permit(|e| {
+            let permitted = f(e);
+            if permitted {
+                log::warn!("permitted error: {e}");
+            }
+            permitted
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+
+    use std::string::{String, ToString};
+    use std::sync::Mutex;
+    use std::vec::Vec;
+
+    static LOGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct CapturingLogger;
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _: &log::Metadata<'_>) -> bool { true }
+
+        fn log(&self, record: &log::Record<'_>) {
+            LOGS.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger;
+
+    fn init() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Warn);
+    }
+
+    fn logged(msg: &str) -> bool { LOGS.lock().unwrap().iter().any(|l| l == msg) }
+
+    #[test]
+    fn permit_and_log() {
+        init();
+        let x: Result<(), &str> = Err("first error");
+
+        assert!(x.permit_logged(|_| true).is_ok());
+        assert!(logged("permitted error: first error"));
+    }
+
+    #[test]
+    fn fail_without_logging() {
+        init();
+        let x: Result<(), &str> = Err("second error");
+
+        assert!(x.permit_logged(|_| false).is_err());
+        assert!(!logged("permitted error: second error"));
+    }
+}
@@ -0,0 +1,72 @@
+//! The [`PermitOption`] convenience trait for [`Option`]s
+
+pub trait PermitOption<T> {
+    fn permit_none(self, default: T) -> Option<T>;
+
+    fn permit_none_with<F>(self, f: F) -> Option<T>
+    where
+        F: FnOnce() -> T;
+}
+
+impl<T> PermitOption<T> for Option<T> {
+    /// Replaces an expected [`None`] with `Some(default)`.
+    ///
+    /// This is equivalent to `self.or(Some(default))`, and mirrors
+    /// `Permit::permit` from the `permit` feature for [`Result`]s. The default
+    /// is evaluated eagerly; see [`permit_none_with`](Self::permit_none_with)
+    /// for a lazy version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::PermitOption;
+    ///
+    /// fn jobs(arg: Option<&str>) -> Option<usize> {
+    ///     arg.and_then(|j| j.parse().ok()).permit_none(1)
+    /// }
+    ///
+    /// assert_eq!(jobs(Some("4")), Some(4));
+    /// assert_eq!(jobs(None), Some(1));
+    /// ```
+    #[inline]
+    fn permit_none(self, default: T) -> Self { self.or(Some(default)) }
+
+    /// Replaces an expected [`None`] with `Some(f())`.
+    ///
+    /// `f` is only called if `self` is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::PermitOption;
+    ///
+    /// let mut calls = 0;
+    /// assert_eq!(Some(2).permit_none_with(|| { calls += 1; 1 }), Some(2));
+    /// assert_eq!(None.permit_none_with(|| { calls += 1; 1 }), Some(1));
+    /// assert_eq!(calls, 1);
+    /// ```
+    #[inline]
+    fn permit_none_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce() -> T,
+    {
+        self.or_else(|| Some(f()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permit_none() {
+        assert_eq!(Some(42).permit_none(0), Some(42));
+        assert_eq!(None.permit_none(0), Some(0));
+    }
+
+    #[test]
+    fn permit_none_with() {
+        assert_eq!(Some(42).permit_none_with(|| unreachable!()), Some(42));
+        assert_eq!(None.permit_none_with(|| 0), Some(0));
+    }
+}
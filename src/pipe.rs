@@ -0,0 +1,88 @@
+//! The [`Pipe`] convenience trait
+
+pub trait Pipe: Sized {
+    fn pipe<R, F: FnOnce(Self) -> R>(self, f: F) -> R;
+
+    fn pipe_ref<R, F: FnOnce(&Self) -> R>(&self, f: F) -> R;
+
+    fn pipe_mut<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R;
+}
+
+impl<T> Pipe for T {
+    /// Passes any `T` to a function, yielding its result.
+    ///
+    /// This lets function calls read left to right, like a method chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Pipe;
+    ///
+    /// fn double(n: i32) -> i32 { n * 2 }
+    ///
+    /// fn describe(n: i32) -> String { format!("got {n}") }
+    ///
+    /// assert_eq!(21.pipe(double).pipe(describe), "got 42");
+    /// ```
+    #[inline]
+    fn pipe<R, F: FnOnce(Self) -> R>(self, f: F) -> R { f(self) }
+
+    /// Passes a reference to any `T` to a function, yielding its result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Pipe;
+    ///
+    /// let name = String::from("tox");
+    ///
+    /// assert_eq!(name.pipe_ref(String::len), 3);
+    /// ```
+    #[inline]
+    fn pipe_ref<R, F: FnOnce(&Self) -> R>(&self, f: F) -> R { f(self) }
+
+    /// Passes a mutable reference to any `T` to a function, yielding its
+    /// result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Pipe;
+    ///
+    /// let mut names = vec!["tox", "wtf"];
+    ///
+    /// assert_eq!(names.pipe_mut(Vec::pop), Some("wtf"));
+    /// assert_eq!(names, ["tox"]);
+    /// ```
+    #[inline]
+    fn pipe_mut<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R { f(self) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipe() {
+        let n: u8 = 21;
+        let wide: u32 = n.pipe(u32::from).pipe(|n| n * 2);
+
+        assert_eq!(wide, 42);
+    }
+
+    #[test]
+    fn pipe_ref() {
+        let numbers = [1, 2, 3];
+
+        assert_eq!(numbers.pipe_ref(|n| n.iter().sum::<i32>()), 6);
+        assert_eq!(numbers, [1, 2, 3]);
+    }
+
+    #[test]
+    fn pipe_mut() {
+        let mut numbers = [3, 1, 2];
+
+        assert_eq!(numbers.pipe_mut(|n| core::mem::replace(&mut n[0], 0)), 3);
+        assert_eq!(numbers, [0, 1, 2]);
+    }
+}
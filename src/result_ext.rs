@@ -0,0 +1,358 @@
+//! Convenience traits for [`Result`]s
+
+#![allow(clippy::missing_errors_doc)]
+
+#[cfg(feature = "std")]
+use core::fmt::Display;
+
+pub trait ResultExt<T, E> {
+    fn map_both<U, F2, M, N>(self, ok: M, err: N) -> Result<U, F2>
+    where
+        M: FnOnce(T) -> U,
+        N: FnOnce(E) -> F2;
+
+    fn err_into<E2: From<E>>(self) -> Result<T, E2>;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    /// Maps the value with `ok` if [`Ok`], or the error with `err` if [`Err`].
+    ///
+    /// This fuses [`Result::map`] and [`Result::map_err`]. Only one of the two
+    /// functions is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::ResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ApiError(String);
+    ///
+    /// fn user_count(raw: &str) -> Result<String, ApiError> {
+    ///     raw.parse::<u32>()
+    ///         .map_both(|n| format!("{n} users"), |e| ApiError(e.to_string()))
+    /// }
+    ///
+    /// assert_eq!(user_count("3").unwrap(), "3 users");
+    /// assert!(user_count("three").is_err());
+    /// ```
+    #[inline]
+    fn map_both<U, F2, M, N>(self, ok: M, err: N) -> Result<U, F2>
+    where
+        M: FnOnce(T) -> U,
+        N: FnOnce(E) -> F2,
+    {
+        match self {
+            | Ok(t) => Ok(ok(t)),
+            | Err(e) => Err(err(e)),
+        }
+    }
+
+    /// Converts the error with [`From`] if [`Err`].
+    ///
+    /// This is `map_err(Into::into)`, the conversion `?` does implicitly, for
+    /// closures and chains where `?` can't be used. [`Ok`] passes through
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use treats::ResultExt;
+    ///
+    /// #[derive(Debug)]
+    /// enum AppError {
+    ///     Io(io::Error),
+    /// }
+    ///
+    /// impl From<io::Error> for AppError {
+    ///     fn from(e: io::Error) -> Self { Self::Io(e) }
+    /// }
+    ///
+    /// let configs = ["/path/to/nonexistent/config", "/path/to/nonexistent/fallback"];
+    /// let contents = configs
+    ///     .iter()
+    ///     .map(|path| std::fs::read_to_string(path).err_into::<AppError>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert!(matches!(contents[0], Err(AppError::Io(_))));
+    /// ```
+    #[inline]
+    fn err_into<E2: From<E>>(self) -> Result<T, E2> { self.map_err(Into::into) }
+}
+
+#[cfg(feature = "std")]
+pub trait ResultReportExt<T, E> {
+    fn ok_or_log(self) -> Option<T>
+    where
+        E: Display;
+
+    fn unwrap_or_report(self, msg: &str) -> T
+    where
+        E: Display;
+
+    fn context_str(self, ctx: &'static str) -> Result<T, String>
+    where
+        E: Display;
+
+    fn context_with<F: FnOnce() -> String>(self, f: F) -> Result<T, String>
+    where
+        E: Display;
+}
+
+/// Formats an error with some context, as
+/// [`ResultReportExt::unwrap_or_report`] prints it before exiting.
+#[cfg(feature = "std")]
+fn report<E: Display>(msg: &str, err: &E) -> String { format!("{msg}: {err}") }
+
+#[cfg(feature = "std")]
+impl<T, E> ResultReportExt<T, E> for Result<T, E> {
+    /// Prints the error to stderr if [`Err`], converting into an [`Option`].
+    ///
+    /// This fuses [`Result::inspect_err`] and [`Result::ok`]. The error is
+    /// printed with its [`Display`] representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::ResultReportExt;
+    ///
+    /// fn jobs(arg: &str) -> usize { arg.parse().ok_or_log().unwrap_or(1) }
+    ///
+    /// assert_eq!(jobs("4"), 4);
+    /// assert_eq!(jobs("four"), 1); // prints "invalid digit found in string"
+    /// ```
+    #[inline]
+    fn ok_or_log(self) -> Option<T>
+    where
+        E: Display,
+    {
+        self.inspect_err(|e| eprintln!("{e}")).ok()
+    }
+
+    /// Yields the [`Ok`] value, or prints `"{msg}: {err}"` to stderr and exits
+    /// with code 1 if [`Err`].
+    ///
+    /// This is meant for the top of a CLI's `main`. Since it calls
+    /// [`std::process::exit`], destructors on the current stack and on other
+    /// threads' stacks don't run, so flush anything that needs it first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use treats::ResultReportExt;
+    ///
+    /// let config = std::fs::read_to_string("/etc/app.conf").unwrap_or_report("failed to start");
+    /// ```
+    fn unwrap_or_report(self, msg: &str) -> T
+    where
+        E: Display,
+    {
+        self.unwrap_or_else(|e| {
+            eprintln!("{}", report(msg, &e));
+            std::process::exit(1)
+        })
+    }
+
+    /// Prefixes the error with `ctx` if [`Err`], as `"{ctx}: {err}"`.
+    ///
+    /// This is a lightweight alternative to `anyhow::Context` for crates that
+    /// are fine with [`String`] errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::ResultReportExt;
+    ///
+    /// let port = "http".parse::<u16>().context_str("invalid port");
+    ///
+    /// assert_eq!(port.unwrap_err(), "invalid port: invalid digit found in string");
+    /// ```
+    #[inline]
+    fn context_str(self, ctx: &'static str) -> Result<T, String>
+    where
+        E: Display,
+    {
+        self.map_err(|e| report(ctx, &e))
+    }
+
+    /// Prefixes the error with the context from `f` if [`Err`], as
+    /// `"{ctx}: {err}"`.
+    ///
+    /// This is the lazy version of [`ResultReportExt::context_str`]. `f` is
+    /// only called if [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::ResultReportExt;
+    ///
+    /// fn read_config(path: &str) -> Result<String, String> {
+    ///     std::fs::read_to_string(path).context_with(|| format!("failed to read {path}"))
+    /// }
+    ///
+    /// assert!(read_config("/path/to/nonexistent/config").is_err());
+    /// ```
+    #[inline]
+    fn context_with<F: FnOnce() -> String>(self, f: F) -> Result<T, String>
+    where
+        E: Display,
+    {
+        self.map_err(|e| report(&f(), &e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+
+    use std::string::{String, ToString};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ok_or_log_ok() {
+        let x: Result<u8, &str> = Ok(42);
+
+        assert_eq!(x.ok_or_log(), Some(42));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ok_or_log_err() {
+        struct DisplayOnly;
+
+        impl Display for DisplayOnly {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("displayed, not debugged")
+            }
+        }
+
+        let x: Result<u8, DisplayOnly> = Err(DisplayOnly);
+
+        assert_eq!(x.ok_or_log(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unwrap_or_report_ok() {
+        let x: Result<u8, &str> = Ok(42);
+
+        assert_eq!(x.unwrap_or_report("unreachable"), 42);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report_format() {
+        let err = "abc".parse::<u8>().unwrap_err();
+
+        assert_eq! {
+            report("failed to start", &err),
+            "failed to start: invalid digit found in string"
+        }
+        assert_eq!(report("", &"bare"), ": bare");
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Code(u16);
+
+    #[test]
+    fn map_both_ok() {
+        let x: Result<u8, u16> = Ok(42);
+
+        assert_eq! {
+            x.map_both(|n| n.to_string(), |_| -> Code { unreachable!() }),
+            Ok(String::from("42"))
+        };
+    }
+
+    #[test]
+    fn map_both_err() {
+        let x: Result<u8, u16> = Err(404);
+
+        assert_eq! {
+            x.map_both(|_| -> String { unreachable!() }, Code),
+            Err(Code(404))
+        };
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Wrapped(&'static str);
+
+    impl From<&'static str> for Wrapped {
+        fn from(e: &'static str) -> Self { Self(e) }
+    }
+
+    #[test]
+    fn err_into_err() {
+        let x: Result<u8, &str> = Err("not found");
+
+        assert_eq!(x.err_into::<Wrapped>(), Err(Wrapped("not found")));
+    }
+
+    #[test]
+    fn err_into_ok() {
+        let x: Result<u8, &str> = Ok(42);
+
+        assert_eq!(x.err_into::<Wrapped>(), Ok(42));
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug)]
+    enum AppError {
+        Io(std::io::Error),
+    }
+
+    #[cfg(feature = "std")]
+    impl From<std::io::Error> for AppError {
+        fn from(e: std::io::Error) -> Self { Self::Io(e) }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn err_into_io_error() {
+        let x = std::fs::read_to_string("/path/to/nonexistent/file").err_into::<AppError>();
+
+        assert!(matches!(x, Err(AppError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn context_str() {
+        let x: Result<u8, &str> = Err("not found");
+
+        assert_eq!(x.context_str("failed to load"), Err(String::from("failed to load: not found")));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn context_with() {
+        let mut called = false;
+        let x: Result<u8, &str> = Err("not found");
+
+        let x = x.context_with(|| {
+            called = true;
+            format!("failed to load {}", "config")
+        });
+        assert_eq!(x, Err(String::from("failed to load config: not found")));
+        assert!(called);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn context_ok() {
+        let mut called = false;
+        let x: Result<u8, &str> = Ok(42);
+
+        assert_eq!(x.context_str("unused"), Ok(42));
+        assert_eq! {
+            x.context_with(|| {
+                called = true;
+                String::new()
+            }),
+            Ok(42)
+        };
+        assert!(!called);
+    }
+}
@@ -0,0 +1,75 @@
+//! The [`ResultInspect`] convenience trait for [`Result`]s
+
+pub trait ResultInspect<T, E> {
+    #[must_use]
+    fn inspect_both<O, R>(self, ok: O, err: R) -> Self
+    where
+        O: FnOnce(&T),
+        R: FnOnce(&E);
+}
+
+impl<T, E> ResultInspect<T, E> for Result<T, E> {
+    /// Calls `ok` with the contained value if [`Ok`], or `err` with the
+    /// contained error if [`Err`].
+    ///
+    /// Returns the original result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::ResultInspect;
+    ///
+    /// fn parse_count(s: &str) -> Result<usize, std::num::ParseIntError> {
+    ///     s.parse().inspect_both(
+    ///         |n| eprintln!("Parsed count {n}"),
+    ///         |e| eprintln!("Failed to parse count '{s}': {e}"),
+    ///     )
+    /// }
+    /// ```
+    #[inline]
+    fn inspect_both<O, R>(self, ok: O, err: R) -> Self
+    where
+        O: FnOnce(&T),
+        R: FnOnce(&E),
+    {
+        match self {
+            | Ok(ref t) => ok(t),
+            | Err(ref e) => err(e),
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inspect_both_ok() {
+        let mut ok_inspected = false;
+        let mut err_inspected = false;
+        let x: Result<i32, &str> = Ok(-3);
+
+        assert_eq! {
+            x.inspect_both(|_| ok_inspected = true, |_| err_inspected = true),
+            Ok(-3)
+        };
+        assert!(ok_inspected);
+        assert!(!err_inspected);
+    }
+
+    #[test]
+    fn inspect_both_err() {
+        let mut ok_inspected = false;
+        let mut err_inspected = false;
+        let x: Result<i32, &str> = Err("some error");
+
+        assert_eq! {
+            x.inspect_both(|_| ok_inspected = true, |_| err_inspected = true),
+            Err("some error")
+        };
+        assert!(!ok_inspected);
+        assert!(err_inspected);
+    }
+}
@@ -0,0 +1,156 @@
+//! Retry fallible closures
+
+#![allow(clippy::missing_errors_doc)]
+
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+/// Calls `f` up to `attempts` times, yielding the first [`Ok`] or the last
+/// [`Err`].
+///
+/// Attempts are made back to back without sleeping. See
+/// `retry_with_backoff` (with the `std` feature) to wait between them.
+///
+/// # Panics
+///
+/// Panics if `attempts` is 0, since there'd be no error to return.
+///
+/// # Examples
+///
+/// ```
+/// use treats::retry;
+///
+/// let mut connections = [Err("refused"), Err("refused"), Ok("connected")].into_iter();
+///
+/// assert_eq!(retry(3, || connections.next().unwrap()), Ok("connected"));
+/// ```
+#[track_caller]
+pub fn retry<T, E, F>(attempts: usize, mut f: F) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    assert!(attempts > 0, "retry needs at least one attempt");
+
+    let mut result = f();
+    for _ in 1..attempts {
+        if result.is_ok() {
+            break;
+        }
+        result = f();
+    }
+    result
+}
+
+/// Calls `f` up to `attempts` times, yielding the first [`Ok`] or the last
+/// [`Err`], sleeping between attempts.
+///
+/// The first sleep lasts `base`, and each one after that lasts twice as long
+/// as the last. There's no sleep after the final attempt.
+///
+/// # Panics
+///
+/// Panics if `attempts` is 0, since there'd be no error to return.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use treats::retry_with_backoff;
+///
+/// // Sleeps 10ms, then 20ms
+/// let mut tries = 0;
+/// let result = retry_with_backoff(3, Duration::from_millis(10), || {
+///     tries += 1;
+///     if tries < 3 { Err("busy") } else { Ok(tries) }
+/// });
+///
+/// assert_eq!(result, Ok(3));
+/// ```
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn retry_with_backoff<T, E, F>(attempts: usize, base: Duration, mut f: F) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    assert!(attempts > 0, "retry_with_backoff needs at least one attempt");
+
+    let mut delay = base;
+    let mut result = f();
+    for _ in 1..attempts {
+        if result.is_ok() {
+            break;
+        }
+        std::thread::sleep(delay);
+        delay = delay.saturating_mul(2);
+        result = f();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn succeed_on_third(tries: &mut usize) -> Result<usize, &'static str> {
+        *tries += 1;
+        if *tries < 3 { Err("not yet") } else { Ok(*tries) }
+    }
+
+    #[test]
+    fn retry_succeeds_on_third() {
+        let mut tries = 0;
+
+        assert_eq!(retry(5, || succeed_on_third(&mut tries)), Ok(3));
+        assert_eq!(tries, 3);
+    }
+
+    #[test]
+    fn retry_always_fails() {
+        let mut tries = 0;
+        let result: Result<(), usize> = retry(4, || {
+            tries += 1;
+            Err(tries)
+        });
+
+        assert_eq!(result, Err(4));
+        assert_eq!(tries, 4);
+    }
+
+    #[test]
+    fn retry_runs_out() {
+        let mut tries = 0;
+
+        assert_eq!(retry(2, || succeed_on_third(&mut tries)), Err("not yet"));
+        assert_eq!(tries, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one attempt")]
+    fn retry_zero_attempts() { let _ = retry(0, || Ok::<_, ()>(())); }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn retry_with_backoff_succeeds_on_third() {
+        let mut tries = 0;
+
+        assert_eq! {
+            retry_with_backoff(5, Duration::from_millis(1), || succeed_on_third(&mut tries)),
+            Ok(3)
+        };
+        assert_eq!(tries, 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn retry_with_backoff_doubles() {
+        use std::time::Instant;
+
+        let start = Instant::now();
+        let result: Result<(), ()> = retry_with_backoff(4, Duration::from_millis(5), || Err(()));
+
+        // 5ms + 10ms + 20ms, with no sleep after the last attempt
+        assert_eq!(result, Err(()));
+        assert!(start.elapsed() >= Duration::from_millis(35));
+    }
+}
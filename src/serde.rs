@@ -0,0 +1,90 @@
+//! [`serde`] helpers for [`Path`](std::path::Path)s
+
+/// Serialize a [`PathBuf`] as a lossy string
+///
+/// Use this with `#[serde(with = "treats::serde::path_as_lossy_string")]`.
+/// Serialization goes through
+/// [`to_lossy_string`](crate::PathToString::to_lossy_string), and
+/// deserialization accepts any string, absolute or relative, unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "treats::serde::path_as_lossy_string")]
+///     data_dir: PathBuf,
+/// }
+/// ```
+///
+/// [`PathBuf`]: std::path::PathBuf
+pub mod path_as_lossy_string {
+    use std::path::{Path, PathBuf};
+
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::PathToString;
+
+    /// Serializes a path as a lossy string.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the serializer.
+    #[inline]
+    pub fn serialize<P, S>(path: P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: AsRef<Path>,
+        S: Serializer,
+    {
+        serializer.serialize_str(&path.as_ref().to_lossy_string())
+    }
+
+    /// Deserializes a path from a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't a string.
+    #[inline]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(PathBuf::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "super::path_as_lossy_string")]
+        data_dir: PathBuf,
+        #[serde(with = "super::path_as_lossy_string")]
+        log_file: PathBuf,
+    }
+
+    #[test]
+    fn path_as_lossy_string_round_trip() {
+        let config = Config {
+            data_dir: PathBuf::from("/var/lib/treats"),
+            log_file: PathBuf::from("logs/treats.log"),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(json, r#"{"data_dir":"/var/lib/treats","log_file":"logs/treats.log"}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn path_as_lossy_string_rejects_non_strings() {
+        assert!(serde_json::from_str::<Config>(r#"{"data_dir":42,"log_file":"a"}"#).is_err());
+    }
+}
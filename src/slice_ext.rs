@@ -0,0 +1,71 @@
+//! The [`SliceExt`] convenience trait for slices
+
+pub trait SliceExt<T> {
+    fn first_last(&self) -> Option<(&T, &T)>;
+
+    fn middle(&self) -> &[T];
+}
+
+impl<T> SliceExt<T> for [T] {
+    /// Yields the first and last elements of a slice, or [`None`] if it's
+    /// empty.
+    ///
+    /// For a slice of one element, both are the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::SliceExt;
+    ///
+    /// let temps = [12, 15, 19, 17];
+    ///
+    /// assert_eq!(temps.first_last(), Some((&12, &17)));
+    /// ```
+    #[inline]
+    fn first_last(&self) -> Option<(&T, &T)> { Some((self.first()?, self.last()?)) }
+
+    /// Yields a slice without its first and last elements.
+    ///
+    /// Slices of fewer than three elements have an empty middle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::SliceExt;
+    ///
+    /// let words = ["<", "treats", "are", "sweet", ">"];
+    ///
+    /// assert_eq!(words.middle(), ["treats", "are", "sweet"]);
+    /// ```
+    #[inline]
+    fn middle(&self) -> &[T] { self.get(1..self.len().saturating_sub(1)).unwrap_or(&[]) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_last() {
+        assert_eq!(<[u8]>::first_last(&[]), None);
+        assert_eq!([1].first_last(), Some((&1, &1)));
+        assert_eq!([1, 2].first_last(), Some((&1, &2)));
+        assert_eq!([1, 2, 3, 4, 5].first_last(), Some((&1, &5)));
+    }
+
+    #[test]
+    fn first_last_same_element() {
+        let one = [42];
+        let (first, last) = one.first_last().unwrap();
+
+        assert!(core::ptr::eq(first, last));
+    }
+
+    #[test]
+    fn middle() {
+        assert!(<[u8]>::middle(&[]).is_empty());
+        assert!([1].middle().is_empty());
+        assert!([1, 2].middle().is_empty());
+        assert_eq!([1, 2, 3, 4, 5].middle(), [2, 3, 4]);
+    }
+}
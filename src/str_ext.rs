@@ -0,0 +1,153 @@
+//! The [`StrExt`] convenience trait for [`str`]s
+
+pub trait StrExt {
+    fn count_matches(&self, pat: &str) -> usize;
+
+    fn count_char(&self, c: char) -> usize;
+
+    fn strip_any_prefix<'a>(&'a self, prefixes: &[&str]) -> Option<&'a str>;
+
+    fn strip_any_suffix<'a>(&'a self, suffixes: &[&str]) -> Option<&'a str>;
+}
+
+impl StrExt for str {
+    /// Counts the occurrences of a substring in a [`str`].
+    ///
+    /// This matches [`str::matches`], so occurrences don't overlap, and an
+    /// empty pattern matches between every [`char`] as well as at both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StrExt;
+    ///
+    /// assert_eq!("one fish two fish".count_matches("fish"), 2);
+    /// assert_eq!("aaaa".count_matches("aa"), 2);
+    /// assert_eq!("abc".count_matches(""), 4);
+    /// ```
+    #[inline]
+    fn count_matches(&self, pat: &str) -> usize { self.matches(pat).count() }
+
+    /// Counts the occurrences of a [`char`] in a [`str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StrExt;
+    ///
+    /// assert_eq!("/usr/local/bin".count_char('/'), 3);
+    /// ```
+    #[inline]
+    fn count_char(&self, c: char) -> usize { self.matches(c).count() }
+
+    /// Strips the first of several prefixes that a [`str`] starts with.
+    ///
+    /// This is like [`str::strip_prefix`] with a list of candidates, tried in
+    /// order, so the first match wins. An empty list yields [`None`], while an
+    /// empty candidate matches everything and strips nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StrExt;
+    ///
+    /// let schemes = ["http://", "https://"];
+    ///
+    /// assert_eq!("https://tox.wtf".strip_any_prefix(&schemes), Some("tox.wtf"));
+    /// assert_eq!("ftp://tox.wtf".strip_any_prefix(&schemes), None);
+    /// ```
+    #[inline]
+    fn strip_any_prefix<'a>(&'a self, prefixes: &[&str]) -> Option<&'a str> {
+        prefixes.iter().find_map(|p| self.strip_prefix(p))
+    }
+
+    /// Strips the first of several suffixes that a [`str`] ends with.
+    ///
+    /// This is like [`str::strip_suffix`] with a list of candidates, tried in
+    /// order, so the first match wins. An empty list yields [`None`], while an
+    /// empty candidate matches everything and strips nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StrExt;
+    ///
+    /// let archives = [".tar.gz", ".gz", ".zip"];
+    ///
+    /// assert_eq!("treats.tar.gz".strip_any_suffix(&archives), Some("treats"));
+    /// assert_eq!("treats.rs".strip_any_suffix(&archives), None);
+    /// ```
+    #[inline]
+    fn strip_any_suffix<'a>(&'a self, suffixes: &[&str]) -> Option<&'a str> {
+        suffixes.iter().find_map(|s| self.strip_suffix(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_matches() {
+        assert_eq!("".count_matches("a"), 0);
+        assert_eq!("treats".count_matches("ea"), 1);
+        assert_eq!("treats".count_matches("xyz"), 0);
+        assert_eq!("🍬🍭🍬".count_matches("🍬"), 2);
+    }
+
+    #[test]
+    fn count_matches_overlapping() {
+        assert_eq!("aaaa".count_matches("aa"), 2);
+        assert_eq!("aaa".count_matches("aa"), 1);
+        assert_eq!("abababa".count_matches("aba"), 2);
+    }
+
+    #[test]
+    fn count_matches_empty_pattern() {
+        assert_eq!("".count_matches(""), 1);
+        assert_eq!("abc".count_matches(""), 4);
+        assert_eq!("naïve".count_matches(""), 6);
+    }
+
+    #[test]
+    fn count_char() {
+        assert_eq!("".count_char('a'), 0);
+        assert_eq!("banana".count_char('a'), 3);
+        assert_eq!("naïve".count_char('ï'), 1);
+        assert_eq!("banana".count_char('z'), 0);
+    }
+
+    #[test]
+    fn strip_any_prefix_schemes() {
+        let schemes = ["http://", "https://", "ftp://"];
+
+        assert_eq!("http://tox.wtf".strip_any_prefix(&schemes), Some("tox.wtf"));
+        assert_eq!("https://tox.wtf".strip_any_prefix(&schemes), Some("tox.wtf"));
+        assert_eq!("ftp://tox.wtf".strip_any_prefix(&schemes), Some("tox.wtf"));
+    }
+
+    #[test]
+    fn strip_any_prefix_no_match() {
+        assert_eq!("tox.wtf".strip_any_prefix(&["http://", "https://"]), None);
+        assert_eq!("".strip_any_prefix(&["http://"]), None);
+        assert_eq!("tox.wtf".strip_any_prefix(&[]), None);
+    }
+
+    #[test]
+    fn strip_any_prefix_first_wins() {
+        assert_eq!("treats".strip_any_prefix(&["t", "tr"]), Some("reats"));
+        assert_eq!("treats".strip_any_prefix(&["tr", "t"]), Some("eats"));
+        assert_eq!("treats".strip_any_prefix(&["", "tr"]), Some("treats"));
+    }
+
+    #[test]
+    fn strip_any_suffix() {
+        let exts = [".tar.gz", ".gz"];
+
+        assert_eq!("treats.tar.gz".strip_any_suffix(&exts), Some("treats"));
+        assert_eq!("treats.gz".strip_any_suffix(&exts), Some("treats"));
+        assert_eq!("treats.rs".strip_any_suffix(&exts), None);
+        assert_eq!("treats".strip_any_suffix(&[]), None);
+        assert_eq!("treats".strip_any_suffix(&[""]), Some("treats"));
+    }
+}
@@ -0,0 +1,84 @@
+//! The [`StrWidthExt`] convenience trait for [`str`]s
+
+pub trait StrWidthExt {
+    fn char_len(&self) -> usize;
+
+    fn is_blank(&self) -> bool;
+
+    fn lines_count(&self) -> usize;
+}
+
+impl StrWidthExt for str {
+    /// Counts the [`char`]s in a [`str`].
+    ///
+    /// Unlike [`str::len`], this counts codepoints rather than bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StrWidthExt;
+    ///
+    /// assert_eq!("naïve".char_len(), 5);
+    /// assert_eq!("naïve".len(), 6);
+    /// ```
+    #[inline]
+    fn char_len(&self) -> usize { self.chars().count() }
+
+    /// Checks whether a [`str`] is empty or only whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StrWidthExt;
+    ///
+    /// assert!(" \t\n".is_blank());
+    /// assert!(!" x ".is_blank());
+    /// ```
+    #[inline]
+    fn is_blank(&self) -> bool { self.chars().all(char::is_whitespace) }
+
+    /// Counts the lines in a [`str`].
+    ///
+    /// This matches [`str::lines`], so an empty [`str`] has no lines and a
+    /// trailing newline doesn't start another one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StrWidthExt;
+    ///
+    /// assert_eq!("a\nb".lines_count(), 2);
+    /// assert_eq!("a\nb\n".lines_count(), 2);
+    /// ```
+    #[inline]
+    fn lines_count(&self) -> usize { self.lines().count() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_len() {
+        assert_eq!("".char_len(), 0);
+        assert_eq!("treats".char_len(), 6);
+        assert_eq!("🍬🍭".char_len(), 2);
+    }
+
+    #[test]
+    fn is_blank() {
+        assert!("".is_blank());
+        assert!("  \t\n".is_blank());
+        assert!("\u{3000}".is_blank());
+        assert!(!"  a  ".is_blank());
+    }
+
+    #[test]
+    fn lines_count() {
+        assert_eq!("".lines_count(), 0);
+        assert_eq!("a".lines_count(), 1);
+        assert_eq!("a\nb".lines_count(), 2);
+        assert_eq!("a\r\nb\n".lines_count(), 2);
+        assert_eq!("\n\n".lines_count(), 2);
+    }
+}
@@ -0,0 +1,78 @@
+//! The [`StringExt`] convenience trait for [`str`]s and [`String`]s
+
+pub trait StringExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+}
+
+impl StringExt for str {
+    /// Truncates a [`str`] to at most `max_chars` characters, ending with `…`
+    /// if anything was cut off.
+    ///
+    /// Characters are counted by [`char`], so multibyte codepoints are never
+    /// split. A `max_chars` of zero yields an empty [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::StringExt;
+    ///
+    /// assert_eq!("treats".truncate_ellipsis(10), "treats");
+    /// assert_eq!("treats".truncate_ellipsis(4), "tre…");
+    /// ```
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        if max_chars == 0 {
+            return String::new();
+        }
+
+        match self.char_indices().nth(max_chars - 1) {
+            // Only truncate if there's more than one character left, since
+            // replacing a single character with `…` gains nothing
+            | Some((end, _)) if self[end..].chars().nth(1).is_some() => {
+                let mut truncated = self[..end].to_string();
+                truncated.push('…');
+                truncated
+            },
+            | _ => self.to_string(),
+        }
+    }
+}
+
+impl StringExt for String {
+    /// Truncates a [`String`] to at most `max_chars` characters, ending with
+    /// `…` if anything was cut off.
+    #[inline]
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        self.as_str().truncate_ellipsis(max_chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_ellipsis_ascii() {
+        assert_eq!("hello world".truncate_ellipsis(20), "hello world");
+        assert_eq!("hello world".truncate_ellipsis(11), "hello world");
+        assert_eq!("hello world".truncate_ellipsis(10), "hello wor…");
+        assert_eq!(String::from("hello world").truncate_ellipsis(5), "hell…");
+    }
+
+    #[test]
+    fn truncate_ellipsis_emoji() {
+        let s = "🍬🍭🍫🍩";
+
+        assert_eq!(s.truncate_ellipsis(4), s);
+        assert_eq!(s.truncate_ellipsis(3), "🍬🍭…");
+        assert_eq!(s.truncate_ellipsis(2), "🍬…");
+    }
+
+    #[test]
+    fn truncate_ellipsis_tiny() {
+        assert_eq!("hello".truncate_ellipsis(0), "");
+        assert_eq!("hello".truncate_ellipsis(1), "…");
+        assert_eq!("h".truncate_ellipsis(1), "h");
+        assert_eq!("".truncate_ellipsis(0), "");
+        assert_eq!("".truncate_ellipsis(1), "");
+    }
+}
@@ -0,0 +1,83 @@
+//! The [`SwapExt`] convenience trait
+
+use core::mem;
+
+pub trait SwapExt: Sized {
+    #[must_use]
+    fn swap_with(&mut self, value: Self) -> Self;
+
+    #[must_use]
+    fn take_default(&mut self) -> Self
+    where
+        Self: Default;
+}
+
+impl<T> SwapExt for T {
+    /// Replaces any `T` in place with `value`.
+    ///
+    /// Returns the old value. This is [`mem::replace`] as a method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::SwapExt;
+    ///
+    /// let mut state = "idle";
+    /// let old = state.swap_with("running");
+    ///
+    /// assert_eq!(old, "idle");
+    /// assert_eq!(state, "running");
+    /// ```
+    #[inline]
+    fn swap_with(&mut self, value: Self) -> Self { mem::replace(self, value) }
+
+    /// Takes any `T` out, leaving [`T::default()`](Default::default) in its
+    /// place.
+    ///
+    /// Returns the old value. This is [`mem::take`] as a method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::SwapExt;
+    ///
+    /// let mut queue = vec![1, 2, 3];
+    /// let drained = queue.take_default();
+    ///
+    /// assert_eq!(drained, [1, 2, 3]);
+    /// assert!(queue.is_empty());
+    /// ```
+    #[inline]
+    fn take_default(&mut self) -> Self
+    where
+        Self: Default,
+    {
+        mem::take(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+
+    use std::string::String;
+    use std::vec;
+    use std::vec::Vec;
+
+    #[test]
+    fn swap_with_string() {
+        let mut name = String::from("old");
+
+        assert_eq!(name.swap_with(String::from("new")), "old");
+        assert_eq!(name, "new");
+    }
+
+    #[test]
+    fn take_default_vec() {
+        let mut numbers = vec![1, 2, 3];
+
+        assert_eq!(numbers.take_default(), [1, 2, 3]);
+        assert_eq!(numbers, Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,172 @@
+//! The [`Tap`] and [`TapResult`] convenience traits
+
+pub trait Tap: Sized {
+    #[must_use]
+    fn tap<F: FnOnce(&Self)>(self, f: F) -> Self;
+
+    #[must_use]
+    fn tap_mut<F: FnOnce(&mut Self)>(self, f: F) -> Self;
+}
+
+impl<T> Tap for T {
+    /// Calls a function with a reference to any `T`.
+    ///
+    /// Returns the original value, so this can be dropped into any method
+    /// chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Tap;
+    ///
+    /// let words = "the quick brown fox"
+    ///     .split(' ')
+    ///     .collect::<Vec<_>>()
+    ///     .tap(|w| eprintln!("Got {} words", w.len()))
+    ///     .join("-");
+    ///
+    /// assert_eq!(words, "the-quick-brown-fox");
+    /// ```
+    #[inline]
+    fn tap<F: FnOnce(&Self)>(self, f: F) -> Self {
+        f(&self);
+        self
+    }
+
+    /// Calls a function with a mutable reference to any `T`.
+    ///
+    /// Returns the modified value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::Tap;
+    ///
+    /// let words = "the quick brown fox"
+    ///     .split(' ')
+    ///     .collect::<Vec<_>>()
+    ///     .tap_mut(|w| w.sort_unstable())
+    ///     .join(" ");
+    ///
+    /// assert_eq!(words, "brown fox quick the");
+    /// ```
+    #[inline]
+    fn tap_mut<F: FnOnce(&mut Self)>(mut self, f: F) -> Self {
+        f(&mut self);
+        self
+    }
+}
+
+pub trait TapResult<T, E> {
+    #[must_use]
+    fn tap_ok<F: FnOnce(&T)>(self, f: F) -> Self;
+
+    #[must_use]
+    fn tap_err<F: FnOnce(&E)>(self, f: F) -> Self;
+}
+
+impl<T, E> TapResult<T, E> for Result<T, E> {
+    /// Calls a function with a reference to the contained value if [`Ok`].
+    ///
+    /// Returns the original result. This is [`Result::inspect`] under the
+    /// [`Tap`] family's naming.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::TapResult;
+    ///
+    /// let port = "8080"
+    ///     .parse::<u16>()
+    ///     .tap_ok(|p| eprintln!("Listening on {p}"))
+    ///     .unwrap_or(80);
+    ///
+    /// assert_eq!(port, 8080);
+    /// ```
+    #[inline]
+    fn tap_ok<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Ok(t) = &self {
+            f(t);
+        }
+
+        self
+    }
+
+    /// Calls a function with a reference to the contained error if [`Err`].
+    ///
+    /// Returns the original result. This is [`Result::inspect_err`] under the
+    /// [`Tap`] family's naming.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::TapResult;
+    ///
+    /// let port = "http"
+    ///     .parse::<u16>()
+    ///     .tap_err(|e| eprintln!("Invalid port: {e}"))
+    ///     .unwrap_or(80);
+    ///
+    /// assert_eq!(port, 80);
+    /// ```
+    #[inline]
+    fn tap_err<F: FnOnce(&E)>(self, f: F) -> Self {
+        if let Err(e) = &self {
+            f(e);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tap() {
+        let mut tapped = 0;
+
+        assert_eq! {
+            (-3).tap(|n| tapped = *n),
+            -3
+        };
+        assert_eq!(tapped, -3);
+    }
+
+    #[test]
+    fn tap_mut() {
+        assert_eq! {
+            [3, 1, 2].tap_mut(|a| a.sort_unstable()),
+            [1, 2, 3]
+        };
+    }
+
+    #[test]
+    fn tap_ok() {
+        let mut ok = None;
+        let mut err = None;
+        let x: Result<u8, &str> = Ok(42);
+
+        assert_eq! {
+            x.tap_ok(|t| ok = Some(*t)).tap_err(|e| err = Some(*e)),
+            Ok(42)
+        };
+        assert_eq!(ok, Some(42));
+        assert_eq!(err, None);
+    }
+
+    #[test]
+    fn tap_err() {
+        let mut ok = None;
+        let mut err = None;
+        let x: Result<u8, &str> = Err("failed");
+
+        assert_eq! {
+            x.tap_ok(|t| ok = Some(*t)).tap_err(|e| err = Some(*e)),
+            Err("failed")
+        };
+        assert_eq!(ok, None);
+        assert_eq!(err, Some("failed"));
+    }
+}
@@ -0,0 +1,16 @@
+//! Fixtures shared by the tests of several modules
+
+// Not every feature combination has tests that use every fixture
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+/// Creates an empty directory named after `name` in the system's temp
+/// directory, removing any leftovers from a previous run first.
+pub fn tempdir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("treats-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
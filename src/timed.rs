@@ -0,0 +1,84 @@
+//! Measure how long closures take
+
+use std::time::{Duration, Instant};
+
+/// Calls `f`, yielding its result along with how long it took.
+///
+/// # Examples
+///
+/// ```
+/// use treats::timed;
+///
+/// let (sum, elapsed) = timed(|| (1..=100).sum::<u32>());
+///
+/// assert_eq!(sum, 5050);
+/// println!("Summed in {elapsed:?}");
+/// ```
+#[inline]
+pub fn timed<T, F>(f: F) -> (T, Duration)
+where
+    F: FnOnce() -> T,
+{
+    let start = Instant::now();
+    let t = f();
+    (t, start.elapsed())
+}
+
+/// Calls `f`, printing how long it took to stderr, labelled with `label`.
+///
+/// This is [`timed`] for quick profiling, passing the result through.
+///
+/// # Examples
+///
+/// ```
+/// use treats::time_it;
+///
+/// // Prints something like "sum: 1.2µs"
+/// let sum = time_it("sum", || (1..=100).sum::<u32>());
+///
+/// assert_eq!(sum, 5050);
+/// ```
+#[inline]
+pub fn time_it<T, F>(label: &str, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let (t, elapsed) = timed(f);
+    eprintln!("{label}: {elapsed:?}");
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+
+    #[test]
+    fn timed_value() {
+        let (value, _) = timed(|| "treats");
+
+        assert_eq!(value, "treats");
+    }
+
+    #[test]
+    fn timed_sleep() {
+        let (value, elapsed) = timed(|| {
+            thread::sleep(Duration::from_millis(10));
+            42
+        });
+
+        assert_eq!(value, 42);
+        assert!(elapsed >= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn time_it_value() {
+        let value = time_it("sleep", || {
+            thread::sleep(Duration::from_millis(1));
+            vec![1, 2, 3]
+        });
+
+        assert_eq!(value, [1, 2, 3]);
+    }
+}
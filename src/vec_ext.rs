@@ -0,0 +1,79 @@
+//! The [`VecExt`] convenience trait for [`Vec`]s
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+pub trait VecExt<T> {
+    fn dedup_preserve_order(&mut self)
+    where
+        T: Eq + Hash;
+}
+
+impl<T> VecExt<T> for Vec<T> {
+    /// Removes every duplicate from a [`Vec`], keeping the first occurrence of
+    /// each element in its original order.
+    ///
+    /// Unlike [`Vec::dedup`], duplicates don't need to be consecutive, and the
+    /// [`Vec`] doesn't need to be sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use treats::VecExt;
+    ///
+    /// let mut tags = vec!["rust", "cli", "rust", "traits", "cli"];
+    /// tags.dedup_preserve_order();
+    ///
+    /// assert_eq!(tags, ["rust", "cli", "traits"]);
+    /// ```
+    fn dedup_preserve_order(&mut self)
+    where
+        T: Eq + Hash,
+    {
+        // Decide what to keep up front, since the set can't borrow elements
+        // while `retain` moves them around
+        let mut seen = HashSet::with_capacity(self.len());
+        let keep = self.iter().map(|t| seen.insert(t)).collect::<Vec<_>>();
+
+        let mut keep = keep.into_iter();
+        self.retain(|_| keep.next().unwrap_or(true));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedup_preserve_order_interleaved() {
+        let mut numbers = vec![3, 1, 3, 2, 1, 3, 4];
+        numbers.dedup_preserve_order();
+
+        assert_eq!(numbers, [3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn dedup_preserve_order_shrinks() {
+        let mut words = vec!["a".to_string(), "b".to_string(), "a".to_string(), "a".to_string()];
+        words.dedup_preserve_order();
+
+        assert_eq!(words.len(), 2);
+        assert_eq!(words, ["a", "b"]);
+    }
+
+    #[test]
+    fn dedup_preserve_order_empty() {
+        let mut empty: Vec<u8> = Vec::new();
+        empty.dedup_preserve_order();
+
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn dedup_preserve_order_unique() {
+        let mut numbers = vec![5, 4, 3, 2, 1];
+        numbers.dedup_preserve_order();
+
+        assert_eq!(numbers, [5, 4, 3, 2, 1]);
+    }
+}